@@ -0,0 +1,118 @@
+/**************************************************************************************************
+ *                                                                                                *
+ * This Source Code Form is subject to the terms of the Mozilla Public                            *
+ * License, v. 2.0. If a copy of the MPL was not distributed with this                            *
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.                                       *
+ *                                                                                                *
+ **************************************************************************************************/
+
+// ======================================== Documentation ======================================= \\
+
+//! Policies deciding what a future does when it gets polled again after having completed, to be
+//! used with [`FutureExt::after_ready()`].
+//!
+//! By default, polling one of the futures of this crate after it has completed polls its futures
+//! again, which might panic or return another output. Wrapping it with
+//! [`FutureExt::after_ready()`] instead makes it drop its futures once it has completed, and follow
+//! the given policy when polled again.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use futures_lite::future;
+//! use futures_either::after::{Pending, Replay};
+//! use futures_either::{either, Either, FutureExt};
+//!
+//! # future::block_on(async {
+//! #
+//! let mut fut = either(future::ready(42), future::ready(false)).after_ready(Replay::new());
+//! assert_eq!(future::poll_once(&mut fut).await, Some(Either::Left(42)));
+//! assert_eq!(future::poll_once(&mut fut).await, Some(Either::Left(42)));
+//!
+//! let mut fut = either(future::ready(42), future::ready(false)).after_ready(Pending);
+//! assert_eq!(future::poll_once(&mut fut).await, Some(Either::Left(42)));
+//! assert_eq!(future::poll_once(&mut fut).await, None);
+//! #
+//! # });
+//! ```
+//!
+//! [`FutureExt::after_ready()`]: crate::FutureExt::after_ready()
+
+// =========================================== Imports ========================================== \\
+
+use core::task::Poll;
+
+// ============================================ Types =========================================== \\
+
+/// A policy deciding what a future returning `T` does when it gets polled again after having
+/// completed.
+pub trait AfterReady<T> {
+    /// Gets called with the output of the future when it completes, before it gets returned.
+    fn ready(&mut self, out: &T);
+
+    /// Gets called instead of polling the future each time it gets polled after having completed.
+    fn poll_again(&mut self) -> Poll<T>;
+}
+
+/// Panics with a clear message when the future gets polled again.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct Panic;
+
+/// Returns [`Poll::Pending`] forever when the future gets polled again, without ever waking up
+/// the task.
+///
+/// [`Poll::Pending`]: core::task::Poll::Pending
+#[derive(Copy, Clone, Default, Debug)]
+pub struct Pending;
+
+/// Returns a clone of the output of the future each time it gets polled again.
+#[derive(Clone, Debug)]
+pub struct Replay<T> {
+    out: Option<T>,
+}
+
+// =========================================== Replay =========================================== \\
+
+impl<T> Replay<T> {
+    /// Returns a new policy, which hasn't stored any output yet.
+    pub const fn new() -> Self {
+        Replay { out: None }
+    }
+}
+
+impl<T> Default for Replay<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ======================================= impl AfterReady ====================================== \\
+
+impl<T> AfterReady<T> for Panic {
+    fn ready(&mut self, _: &T) {}
+
+    fn poll_again(&mut self) -> Poll<T> {
+        panic!("future polled after completion");
+    }
+}
+
+impl<T> AfterReady<T> for Pending {
+    fn ready(&mut self, _: &T) {}
+
+    fn poll_again(&mut self) -> Poll<T> {
+        Poll::Pending
+    }
+}
+
+impl<T> AfterReady<T> for Replay<T>
+where
+    T: Clone,
+{
+    fn ready(&mut self, out: &T) {
+        self.out = Some(out.clone());
+    }
+
+    fn poll_again(&mut self) -> Poll<T> {
+        Poll::Ready(self.out.clone().expect("`out` should have been set"))
+    }
+}
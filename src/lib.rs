@@ -33,239 +33,10758 @@
 //! # });
 //! ```
 
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(feature = "nightly", feature(try_trait_v2, try_trait_v2_residual))]
+
 // =========================================== Imports ========================================== \\
 
 pub use either::Either;
 
-use core::future::Future;
-use core::pin::Pin;
-use core::task::{Context, Poll};
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(any(feature = "std", feature = "fair", feature = "async-io", feature = "futures-timer"))]
+extern crate std;
 
-// ============================================ Types =========================================== \\
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::task::Wake;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
-/// The [`Future`s] returned by this crate's functions.
-///
-/// [`Future`s]: core::future::Future
-pub mod futs {
-    /// The [`Future`] returned by [`either()`].
-    ///
-    /// [`Future`]: core::future::Future
-    /// [`either()`]: crate::either()
-    pub struct Either<L, R> {
-        pub(super) left: L,
-        pub(super) right: R,
-    }
+#[cfg(feature = "std")]
+use core::any::Any;
+use core::any::type_name;
+#[cfg(feature = "fair")]
+use core::borrow::BorrowMut;
+use core::cell::Cell;
+#[cfg(feature = "alloc")]
+use core::cell::RefCell;
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use core::mem;
+use core::ops::{BitOr, ControlFlow};
+#[cfg(feature = "nightly")]
+use core::ops::{FromResidual, Residual, Try};
+use core::pin::{pin, Pin};
+#[cfg(feature = "alloc")]
+use core::sync::atomic::AtomicBool;
+#[cfg(feature = "alloc")]
+use core::sync::atomic::Ordering;
+use core::task::{Context, Poll, Waker};
+use core::time::Duration;
+#[cfg(feature = "futures-core")]
+use futures_core::future::FusedFuture;
+#[cfg(feature = "std")]
+use std::panic::{catch_unwind, AssertUnwindSafe, UnwindSafe};
+#[cfg(feature = "std")]
+use std::sync::{Mutex, MutexGuard, PoisonError};
+#[cfg(all(debug_assertions, feature = "std"))]
+use core::sync::atomic::AtomicU32;
+#[cfg(all(debug_assertions, feature = "std"))]
+use std::sync::RwLock;
+#[cfg(feature = "stream")]
+use futures_core::Stream;
 
-    #[cfg(feature = "fair")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "fair")))]
-    /// The [`Future`] returned by [`either_fair()`].
-    ///
-    /// [`Future`]: core::future::Future
-    /// [`either_fair()`]: crate::either_fair()
-    pub struct EitherFair<L, R> {
-        pub(super) left: L,
-        pub(super) right: R,
-    }
+use after::AfterReady;
+use order::PollOrder;
+#[cfg(feature = "fair-lite")]
+use order::Random;
+use spawn::Spawn;
+use time::{Elapsed, Timer};
 
-    /// The [`Future`] returned by [`try_either()`].
-    ///
-    /// [`Future`]: core::future::Future
-    /// [`try_either()`]: crate::try_either()
-    pub struct TryEither<L, R> {
-        pub(super) fut: Either<L, R>,
-    }
+// =========================================== Modules ========================================== \\
 
-    #[cfg(feature = "fair")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "fair")))]
-    /// The [`Future`] returned by [`try_either_fair()`].
-    ///
-    /// [`Future`]: core::future::Future
-    /// [`try_either_fair()`]: crate::try_either_fair()
-    pub struct TryEitherFair<L, R> {
-        pub(super) fut: EitherFair<L, R>,
-    }
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod pool;
+
+pub mod after;
+pub mod order;
+pub mod prelude;
+pub mod spawn;
+pub mod time;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use pin_project_lite::pin_project;
 }
 
-// ========================================== either() ========================================== \\
+// =========================================== Macros =========================================== \\
 
-/// Returns a future polling two futures and returning the output of the first one to complete.
-///
-/// The returned future will always poll `left` first; for a "fair" alternative, see
-/// [`either_fair()`].
-///
-/// ## Example
-///
-/// ```rust
-/// use futures_lite::future;
-/// use futures_either::{either, Either};
-///
-/// # future::block_on(async {
-/// #
-/// let out = either(
-///     async { 42 },
-///     async { false },
-/// ).await;
-/// assert_eq!(out, Either::Left(42));
-///
-/// let out = either(
-///     future::pending::<bool>(),
-///     async { 42 },
-/// ).await;
-/// assert_eq!(out, Either::Right(42));
-/// #
-/// # });
-/// ```
-pub fn either<L, R>(left: L, right: R) -> futs::Either<L, R>
-where
-    L: Future,
-    R: Future,
-{
-    futs::Either { left, right }
+macro_rules! either_n {
+    (@struct $(#[$meta:meta])* $Fut:ident { $($field:ident: $T:ident),+ }) => {
+        pin_project! {
+            $(#[$meta])*
+            pub struct $Fut<$($T),+> {
+                $(#[pin] pub(super) $field: $T,)+
+            }
+        }
+    };
+    (@try_struct $(#[$meta:meta])* $TryFut:ident($Fut:ident<$($T:ident),+>)) => {
+        pin_project! {
+            $(#[$meta])*
+            pub struct $TryFut<$($T),+> {
+                #[pin]
+                pub(super) fut: $Fut<$($T),+>,
+            }
+        }
+    };
+    (@map $Enum:ident [$($bv:ident $bt:ident)*] []) => {};
+    (@map
+        $Enum:ident
+        [$($bv:ident $bt:ident)*]
+        [$v:ident $t:ident $map:ident $($av:ident $at:ident $amap:ident)*]
+    ) => {
+        impl<$($bt,)* $t, $($at),*> $Enum<$($bt,)* $t, $($at),*> {
+            #[doc = concat!(
+                "Applies `f` to the value if it is [`", stringify!($v), "`](Self::", stringify!($v),
+                "), leaving it untouched otherwise.",
+            )]
+            pub fn $map<M, T>(self, f: M) -> $Enum<$($bt,)* T, $($at),*>
+            where
+                M: FnOnce($t) -> T,
+            {
+                match self {
+                    $(Self::$bv(out) => $Enum::$bv(out),)*
+                    Self::$v(out) => $Enum::$v(f(out)),
+                    $(Self::$av(out) => $Enum::$av(out),)*
+                }
+            }
+        }
+
+        either_n!(@map $Enum [$($bv $bt)* $v $t] [$($av $at $amap)*]);
+    };
+    (@or $Fut:ident { $($field:ident: $T:ident),+ } => $Next:ident { $next:ident: $N:ident }) => {
+        impl<$($T),+> futs::$Fut<$($T),+> {
+            #[doc = concat!(
+                "Returns a future polling the futures of this one and `", stringify!($next), "`, ",
+                "and returning the output of the first one to complete as an [`", stringify!($Next),
+                "`](crate::", stringify!($Next), ").",
+            )]
+            ///
+            /// This takes precedence over [`FutureExt::or()`], so that chained calls don't result
+            /// in nested outputs.
+            pub fn or<$N>(self, $next: $N) -> futs::$Next<$($T,)+ $N> {
+                futs::$Next { $($field: self.$field,)+ $next }
+            }
+        }
+
+        impl<$($T,)+ $N> BitOr<$N> for futs::$Fut<$($T),+> {
+            type Output = futs::$Next<$($T,)+ $N>;
+
+            fn bitor(self, $next: $N) -> Self::Output {
+                self.or($next)
+            }
+        }
+    };
+    (@same $_t:ident) => { T };
+    (
+        $(#[$meta:meta])*
+        fn $fn:ident -> futs::$Fut:ident, $Enum:ident {
+            $($v:ident($T:ident, $O:ident, $field:ident) { $is:ident, $map:ident, $into:ident }),+
+            $(,)?
+        }
+
+        $(#[$try_meta:meta])*
+        fn $try_fn:ident -> futs::$TryFut:ident;
+    ) => {
+        #[doc = concat!(
+            "The output of [`", stringify!($fn), "()`], indicating which future completed first.",
+        )]
+        #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+        pub enum $Enum<$($T),+> {
+            $(
+                #[doc = concat!("The output of the `", stringify!($field), "` future.")]
+                $v($T),
+            )+
+        }
+
+        impl<$($T),+> $Enum<$($T),+> {
+            $(
+                #[doc = concat!(
+                    "Returns `true` if the value is [`", stringify!($v), "`](Self::",
+                    stringify!($v), ").",
+                )]
+                pub fn $is(&self) -> bool {
+                    matches!(self, Self::$v(_))
+                }
+
+                #[doc = concat!(
+                    "Returns the value if it is [`", stringify!($v), "`](Self::", stringify!($v),
+                    "), or `None` otherwise.",
+                )]
+                pub fn $into(self) -> Option<$T> {
+                    if let Self::$v(out) = self {
+                        Some(out)
+                    } else {
+                        None
+                    }
+                }
+            )+
+        }
+
+        either_n!(@map $Enum [] [$($v $T $map)+]);
+
+        impl<T> $Enum<$(either_n!(@same $T)),+> {
+            /// Returns the value, whichever future it is the output of.
+            pub fn into_inner(self) -> T {
+                match self {
+                    $(Self::$v(out) => out,)+
+                }
+            }
+        }
+
+        $(#[$meta])*
+        #[allow(clippy::too_many_arguments)]
+        pub const fn $fn<$($T),+>($($field: $T),+) -> futs::$Fut<$($T),+>
+        where
+            $($T: Future,)+
+        {
+            futs::$Fut { $($field),+ }
+        }
+
+        impl<$($T),+> Future for futs::$Fut<$($T),+>
+        where
+            $($T: Future,)+
+        {
+            type Output = $Enum<$($T::Output),+>;
+
+            fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+                let this = self.project();
+
+                $(
+                    let fut = this.$field;
+                    if let Poll::Ready(out) = fut.poll(ctx) {
+                        return Poll::Ready($Enum::$v(out));
+                    }
+                )+
+
+                Poll::Pending
+            }
+        }
+
+        $(#[$try_meta])*
+        #[allow(clippy::too_many_arguments)]
+        pub const fn $try_fn<$($O,)+ Error, $($T),+>($($field: $T),+) -> futs::$TryFut<$($T),+>
+        where
+            $($T: Future<Output = Result<$O, Error>>,)+
+        {
+            futs::$TryFut { fut: $fn($($field),+), }
+        }
+
+        impl<$($O,)+ Error, $($T),+> Future for futs::$TryFut<$($T),+>
+        where
+            $($T: Future<Output = Result<$O, Error>>,)+
+        {
+            type Output = Result<$Enum<$($O),+>, Error>;
+
+            fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+                let this = self.project();
+
+                if let Poll::Ready(out) = this.fut.poll(ctx) {
+                    match out {
+                        $(
+                            $Enum::$v(Ok(out)) => Ok($Enum::$v(out)),
+                            $Enum::$v(Err(err)) => Err(err),
+                        )+
+                    }.into()
+                } else {
+                    Poll::Pending
+                }
+            }
+        }
+
+        impl<$($T),+> Race for ($($T,)+)
+        where
+            $($T: Future,)+
+        {
+            type Output = $Enum<$($T::Output),+>;
+            type Future = futs::$Fut<$($T),+>;
+
+            fn race(self) -> Self::Future {
+                let ($($field,)+) = self;
+                $fn($($field),+)
+            }
+        }
+
+        impl<$($O,)+ Error, $($T),+> TryRace for ($($T,)+)
+        where
+            $($T: Future<Output = Result<$O, Error>>,)+
+        {
+            type Ok = $Enum<$($O),+>;
+            type Error = Error;
+            type Future = futs::$TryFut<$($T),+>;
+
+            fn try_race(self) -> Self::Future {
+                let ($($field,)+) = self;
+                $try_fn($($field),+)
+            }
+        }
+    };
 }
 
-// ======================================== either_fair() ======================================= \\
+// ============================================ Types =========================================== \\
 
-#[cfg(feature = "fair")]
-#[cfg_attr(docsrs, doc(cfg(feature = "fair")))]
-/// Returns a future polling two futures and returning the output of the first one to complete.
+/// The [`Future`s] returned by this crate's functions.
 ///
-/// The returned future will choose which future to poll first randomly, each time it is being
-/// polled; for an "unfair" alternative, see [`either()`].
+/// None of them require being pinned by themselves: they implement [`Unpin`] as long as the
+/// futures (and closures) they contain do, which allows to poll them through `&mut` and to move
+/// them after they have been polled.
 ///
 /// ## Example
 ///
 /// ```rust
 /// use futures_lite::future;
-/// use futures_either::{either_fair, Either};
+/// use futures_either::{either, Either};
 ///
 /// # future::block_on(async {
 /// #
-/// let out = either_fair(
-///     async { 42 },
-///     async { false },
-/// ).await;
-/// assert!(out == Either::Left(42) || out == Either::Right(false));
+/// let mut fut = either(future::ready(42), future::pending::<bool>());
+/// assert_eq!((&mut fut).await, Either::Left(42));
 ///
-/// let out = either_fair(
-///     future::pending::<bool>(),
-///     async { 42 },
-/// ).await;
-/// assert_eq!(out, Either::Right(42));
+/// let (_, right) = fut.into_inner();
+/// assert_eq!(future::poll_once(right).await, None);
 /// #
 /// # });
 /// ```
-pub fn either_fair<L, R>(left: L, right: R) -> futs::EitherFair<L, R>
+///
+/// [`Future`s]: core::future::Future
+pub mod futs {
+    use pin_project_lite::pin_project;
+
+    pin_project! {
+        /// The [`Future`] returned by [`either()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either()`]: crate::either()
+        #[derive(Clone, Copy)]
+        pub struct Either<L, R> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+            pub(super) done: bool,
+            pub(super) spins: crate::Spins,
+        }
+    }
+
+    #[cfg(feature = "fair-lite")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+        /// The [`Future`] returned by [`either_fair()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either_fair()`]: crate::either_fair()
+        #[derive(Clone, Copy)]
+        pub struct EitherFair<L, R> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+            // The generator choosing which future to poll first, seeded when first polled and
+            // again whenever the seed epoch of the polling thread changes. Unused while `Random`
+            // has a custom source or uses the entropy source of the operating system.
+            pub(super) state: crate::FairState,
+            pub(super) done: bool,
+            pub(super) spins: crate::Spins,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`try_either()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either()`]: crate::try_either()
+        #[derive(Clone, Copy)]
+        pub struct TryEither<L, R> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+            pub(super) done: bool,
+            pub(super) spins: crate::Spins,
+        }
+    }
+
+    #[cfg(feature = "fair-lite")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+        /// The [`Future`] returned by [`try_either_fair()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either_fair()`]: crate::try_either_fair()
+        #[derive(Clone, Copy)]
+        pub struct TryEitherFair<L, R> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+            // The generator choosing which future to poll first, as for `EitherFair`.
+            pub(super) state: crate::FairState,
+            pub(super) done: bool,
+            pub(super) spins: crate::Spins,
+        }
+    }
+
+    either_n!(@struct
+        /// The [`Future`] returned by [`either3()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either3()`]: crate::either3()
+        Either3 { first: A, second: B, third: C }
+    );
+
+    either_n!(@try_struct
+        /// The [`Future`] returned by [`try_either3()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either3()`]: crate::try_either3()
+        TryEither3(Either3<A, B, C>)
+    );
+
+    either_n!(@struct
+        /// The [`Future`] returned by [`either4()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either4()`]: crate::either4()
+        Either4 { first: A, second: B, third: C, fourth: D }
+    );
+
+    either_n!(@try_struct
+        /// The [`Future`] returned by [`try_either4()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either4()`]: crate::try_either4()
+        TryEither4(Either4<A, B, C, D>)
+    );
+
+    either_n!(@struct
+        /// The [`Future`] returned by [`either5()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either5()`]: crate::either5()
+        Either5 { first: A, second: B, third: C, fourth: D, fifth: E }
+    );
+
+    either_n!(@try_struct
+        /// The [`Future`] returned by [`try_either5()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either5()`]: crate::try_either5()
+        TryEither5(Either5<A, B, C, D, E>)
+    );
+
+    either_n!(@struct
+        /// The [`Future`] returned by [`either6()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either6()`]: crate::either6()
+        Either6 { first: A, second: B, third: C, fourth: D, fifth: E, sixth: F }
+    );
+
+    either_n!(@try_struct
+        /// The [`Future`] returned by [`try_either6()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either6()`]: crate::try_either6()
+        TryEither6(Either6<A, B, C, D, E, F>)
+    );
+
+    either_n!(@struct
+        /// The [`Future`] returned by [`either7()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either7()`]: crate::either7()
+        Either7 { first: A, second: B, third: C, fourth: D, fifth: E, sixth: F, seventh: G }
+    );
+
+    either_n!(@try_struct
+        /// The [`Future`] returned by [`try_either7()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either7()`]: crate::try_either7()
+        TryEither7(Either7<A, B, C, D, E, F, G>)
+    );
+
+    either_n!(@struct
+        /// The [`Future`] returned by [`either8()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either8()`]: crate::either8()
+        Either8 {
+            first: A, second: B, third: C, fourth: D, fifth: E, sixth: F, seventh: G, eighth: H
+        }
+    );
+
+    either_n!(@try_struct
+        /// The [`Future`] returned by [`try_either8()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either8()`]: crate::try_either8()
+        TryEither8(Either8<A, B, C, D, E, F, G, H>)
+    );
+
+    either_n!(@struct
+        /// The [`Future`] returned by [`either9()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either9()`]: crate::either9()
+        Either9 {
+            first: A, second: B, third: C, fourth: D, fifth: E, sixth: F, seventh: G, eighth: H,
+            ninth: I
+        }
+    );
+
+    either_n!(@try_struct
+        /// The [`Future`] returned by [`try_either9()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either9()`]: crate::try_either9()
+        TryEither9(Either9<A, B, C, D, E, F, G, H, I>)
+    );
+
+    either_n!(@struct
+        /// The [`Future`] returned by [`either10()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either10()`]: crate::either10()
+        Either10 {
+            first: A, second: B, third: C, fourth: D, fifth: E, sixth: F, seventh: G, eighth: H,
+            ninth: I, tenth: J
+        }
+    );
+
+    either_n!(@try_struct
+        /// The [`Future`] returned by [`try_either10()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either10()`]: crate::try_either10()
+        TryEither10(Either10<A, B, C, D, E, F, G, H, I, J>)
+    );
+
+    either_n!(@struct
+        /// The [`Future`] returned by [`either11()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either11()`]: crate::either11()
+        Either11 {
+            first: A, second: B, third: C, fourth: D, fifth: E, sixth: F, seventh: G, eighth: H,
+            ninth: I, tenth: J, eleventh: K
+        }
+    );
+
+    either_n!(@try_struct
+        /// The [`Future`] returned by [`try_either11()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either11()`]: crate::try_either11()
+        TryEither11(Either11<A, B, C, D, E, F, G, H, I, J, K>)
+    );
+
+    either_n!(@struct
+        /// The [`Future`] returned by [`either12()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either12()`]: crate::either12()
+        Either12 {
+            first: A, second: B, third: C, fourth: D, fifth: E, sixth: F, seventh: G, eighth: H,
+            ninth: I, tenth: J, eleventh: K, twelfth: L
+        }
+    );
+
+    either_n!(@try_struct
+        /// The [`Future`] returned by [`try_either12()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either12()`]: crate::try_either12()
+        TryEither12(Either12<A, B, C, D, E, F, G, H, I, J, K, L>)
+    );
+
+    /// The [`Future`] returned by [`race_array()`].
+    ///
+    /// [`Future`]: core::future::Future
+    /// [`race_array()`]: crate::race_array()
+    pub struct RaceArray<F, const N: usize> {
+        pub(super) futs: [F; N],
+    }
+
+    #[cfg(feature = "alloc")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        /// The [`Future`] returned by [`race_iter()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`race_iter()`]: crate::race_iter()
+        pub struct RaceIter<F> {
+            pub(super) futs: alloc::boxed::Box<[core::pin::Pin<alloc::boxed::Box<F>>]>,
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        /// The [`Future`] returned by [`select_ok()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`select_ok()`]: crate::select_ok()
+        pub struct SelectOk<F, E> {
+            pub(super) futs: alloc::boxed::Box<[Option<core::pin::Pin<alloc::boxed::Box<F>>>]>,
+            pub(super) errs: alloc::vec::Vec<Option<E>>,
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        /// The [`Future`] returned by [`quorum()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`quorum()`]: crate::quorum()
+        pub struct Quorum<F, T> {
+            pub(super) futs: alloc::boxed::Box<[Option<core::pin::Pin<alloc::boxed::Box<F>>>]>,
+            pub(super) outs: alloc::vec::Vec<(usize, T)>,
+            pub(super) k: usize,
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        /// The [`Future`] returned by [`all_settled()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`all_settled()`]: crate::all_settled()
+        pub struct AllSettled<F, T> {
+            pub(super) futs: alloc::boxed::Box<[Option<core::pin::Pin<alloc::boxed::Box<F>>>]>,
+            pub(super) outs: alloc::vec::Vec<Option<T>>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`race()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`race()`]: crate::race()
+        pub struct Race<L, R> {
+            #[pin]
+            pub(super) fut: Either<L, R>,
+        }
+    }
+
+    #[cfg(feature = "fair-lite")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+        /// The [`Future`] returned by [`race_fair()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`race_fair()`]: crate::race_fair()
+        pub struct RaceFair<L, R> {
+            #[pin]
+            pub(super) fut: EitherFair<L, R>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`race_indexed()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`race_indexed()`]: crate::race_indexed()
+        pub struct RaceIndexed<L, R> {
+            #[pin]
+            pub(super) fut: Either<L, R>,
+        }
+    }
+
+    #[cfg(feature = "fair-lite")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+        /// The [`Future`] returned by [`race_indexed_fair()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`race_indexed_fair()`]: crate::race_indexed_fair()
+        pub struct RaceIndexedFair<L, R> {
+            #[pin]
+            pub(super) fut: EitherFair<L, R>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`try_race()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_race()`]: crate::try_race()
+        pub struct TryRace<L, R> {
+            #[pin]
+            pub(super) fut: Either<L, R>,
+        }
+    }
+
+    #[cfg(feature = "fair-lite")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+        /// The [`Future`] returned by [`try_race_fair()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_race_fair()`]: crate::try_race_fair()
+        pub struct TryRaceFair<L, R> {
+            #[pin]
+            pub(super) fut: EitherFair<L, R>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`race_ok()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`race_ok()`]: crate::race_ok()
+        pub struct RaceOk<L, R, EL, ER> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+            pub(super) left_err: Option<EL>,
+            pub(super) right_err: Option<ER>,
+        }
+    }
+
+    #[cfg(feature = "fair-lite")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+        /// The [`Future`] returned by [`race_ok_fair()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`race_ok_fair()`]: crate::race_ok_fair()
+        pub struct RaceOkFair<L, R, EL, ER> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+            pub(super) left_err: Option<EL>,
+            pub(super) right_err: Option<ER>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`try_either_sided()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either_sided()`]: crate::try_either_sided()
+        pub struct TryEitherSided<L, R> {
+            #[pin]
+            pub(super) fut: Either<L, R>,
+        }
+    }
+
+    #[cfg(feature = "fair-lite")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+        /// The [`Future`] returned by [`try_either_sided_fair()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either_sided_fair()`]: crate::try_either_sided_fair()
+        pub struct TryEitherSidedFair<L, R> {
+            #[pin]
+            pub(super) fut: EitherFair<L, R>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`try_either_err_into()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either_err_into()`]: crate::try_either_err_into()
+        pub struct TryEitherErrInto<L, R, E> {
+            #[pin]
+            pub(super) fut: Either<L, R>,
+            pub(super) _err: core::marker::PhantomData<fn() -> E>,
+        }
+    }
+
+    #[cfg(feature = "fair-lite")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+        /// The [`Future`] returned by [`try_either_err_into_fair()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either_err_into_fair()`]: crate::try_either_err_into_fair()
+        pub struct TryEitherErrIntoFair<L, R, E> {
+            #[pin]
+            pub(super) fut: EitherFair<L, R>,
+            pub(super) _err: core::marker::PhantomData<fn() -> E>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`try_either_errs()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either_errs()`]: crate::try_either_errs()
+        pub struct TryEitherErrs<L, R> {
+            #[pin]
+            pub(super) fut: Either<L, R>,
+        }
+    }
+
+    #[cfg(feature = "fair-lite")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+        /// The [`Future`] returned by [`try_either_errs_fair()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either_errs_fair()`]: crate::try_either_errs_fair()
+        pub struct TryEitherErrsFair<L, R> {
+            #[pin]
+            pub(super) fut: EitherFair<L, R>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`either_control_flow()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either_control_flow()`]: crate::either_control_flow()
+        pub struct EitherControlFlow<L, R> {
+            #[pin]
+            pub(super) fut: Either<L, R>,
+        }
+    }
+
+    #[cfg(feature = "fair-lite")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+        /// The [`Future`] returned by [`either_control_flow_fair()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either_control_flow_fair()`]: crate::either_control_flow_fair()
+        pub struct EitherControlFlowFair<L, R> {
+            #[pin]
+            pub(super) fut: EitherFair<L, R>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`either_some()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either_some()`]: crate::either_some()
+        pub struct EitherSome<L, R> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+            pub(super) left_none: bool,
+            pub(super) right_none: bool,
+        }
+    }
+
+    #[cfg(feature = "fair-lite")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+        /// The [`Future`] returned by [`either_some_fair()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either_some_fair()`]: crate::either_some_fair()
+        pub struct EitherSomeFair<L, R> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+            pub(super) left_none: bool,
+            pub(super) right_none: bool,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`try_either_opt()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either_opt()`]: crate::try_either_opt()
+        pub struct TryEitherOpt<L, R> {
+            #[pin]
+            pub(super) fut: Either<L, R>,
+        }
+    }
+
+    #[cfg(feature = "fair-lite")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+        /// The [`Future`] returned by [`try_either_opt_fair()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either_opt_fair()`]: crate::try_either_opt_fair()
+        pub struct TryEitherOptFair<L, R> {
+            #[pin]
+            pub(super) fut: EitherFair<L, R>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`try_both()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_both()`]: crate::try_both()
+        pub struct TryBoth<L, R, OL, OR> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+            pub(super) left_out: Option<OL>,
+            pub(super) right_out: Option<OR>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`both()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`both()`]: crate::both()
+        pub struct Both<L, R, OL, OR> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+            pub(super) left_out: Option<OL>,
+            pub(super) right_out: Option<OR>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`either_or_both()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either_or_both()`]: crate::either_or_both()
+        pub struct EitherOrBoth<L, R> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`try_either_recover()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either_recover()`]: crate::try_either_recover()
+        pub struct TryEitherRecover<L, R, FL, FR> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+            pub(super) recover_left: FL,
+            pub(super) recover_right: FR,
+        }
+    }
+
+    #[cfg(feature = "nightly")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "nightly")))]
+        /// The [`Future`] returned by [`try_either_generic()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either_generic()`]: crate::try_either_generic()
+        pub struct TryEitherGeneric<L, R> {
+            #[pin]
+            pub(super) fut: Either<L, R>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`try_either_prefer_ok()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either_prefer_ok()`]: crate::try_either_prefer_ok()
+        pub struct TryEitherPreferOk<L, R> {
+            #[pin]
+            pub(super) fut: EitherOrBoth<L, R>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`either_map()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either_map()`]: crate::either_map()
+        pub struct EitherMap<L, R, FL, FR> {
+            #[pin]
+            pub(super) fut: Either<L, R>,
+            pub(super) map_left: Option<FL>,
+            pub(super) map_right: Option<FR>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`either_into()`] and the `unify()` methods.
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either_into()`]: crate::either_into()
+        pub struct Unify<F, T> {
+            #[pin]
+            pub(super) fut: F,
+            pub(super) _out: core::marker::PhantomData<fn() -> T>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`fallback()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`fallback()`]: crate::fallback()
+        pub struct Fallback<L, R, F> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: Option<R>,
+            pub(super) make_right: Option<F>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`fallback_after()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`fallback_after()`]: crate::fallback_after()
+        pub struct FallbackAfter<L, D, R, F> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) delay: Option<D>,
+            #[pin]
+            pub(super) right: Option<R>,
+            pub(super) make_right: Option<F>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`hedge()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`hedge()`]: crate::hedge()
+        pub struct Hedge<F, D, Fut> {
+            #[pin]
+            pub(super) fut: FallbackAfter<Fut, D, Fut, F>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`left()`] and [`right()`], polling either a left or right
+        /// future.
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`left()`]: crate::left()
+        /// [`right()`]: crate::right()
+        pub struct Branch<L, R> {
+            #[pin]
+            pub(super) fut: crate::Either<L, R>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`Either::map_left()`] and [`EitherFair::map_left()`].
+        ///
+        /// [`Future`]: core::future::Future
+        pub struct MapLeft<F, M> {
+            #[pin]
+            pub(super) fut: F,
+            pub(super) map: Option<M>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`Either::map_right()`] and [`EitherFair::map_right()`].
+        ///
+        /// [`Future`]: core::future::Future
+        pub struct MapRight<F, M> {
+            #[pin]
+            pub(super) fut: F,
+            pub(super) map: Option<M>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`Either::map_either()`] and [`EitherFair::map_either()`].
+        ///
+        /// [`Future`]: core::future::Future
+        pub struct MapEither<F, ML, MR> {
+            #[pin]
+            pub(super) fut: F,
+            pub(super) map_left: Option<ML>,
+            pub(super) map_right: Option<MR>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`Either::inspect_left()`] and
+        /// [`EitherFair::inspect_left()`].
+        ///
+        /// [`Future`]: core::future::Future
+        pub struct InspectLeft<F, I> {
+            #[pin]
+            pub(super) fut: F,
+            pub(super) inspect: Option<I>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`Either::inspect_right()`] and
+        /// [`EitherFair::inspect_right()`].
+        ///
+        /// [`Future`]: core::future::Future
+        pub struct InspectRight<F, I> {
+            #[pin]
+            pub(super) fut: F,
+            pub(super) inspect: Option<I>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`TryEither::inspect_ok()`] and
+        /// [`TryEitherFair::inspect_ok()`].
+        ///
+        /// [`Future`]: core::future::Future
+        pub struct InspectOk<F, I> {
+            #[pin]
+            pub(super) fut: F,
+            pub(super) inspect: Option<I>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`TryEither::inspect_err()`] and
+        /// [`TryEitherFair::inspect_err()`].
+        ///
+        /// [`Future`]: core::future::Future
+        pub struct InspectErr<F, I> {
+            #[pin]
+            pub(super) fut: F,
+            pub(super) inspect: Option<I>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`TryEither::map_ok()`] and [`TryEitherFair::map_ok()`].
+        ///
+        /// [`Future`]: core::future::Future
+        pub struct MapOk<F, M> {
+            #[pin]
+            pub(super) fut: F,
+            pub(super) map: Option<M>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`TryEither::map_err()`] and [`TryEitherFair::map_err()`].
+        ///
+        /// [`Future`]: core::future::Future
+        pub struct MapErr<F, M> {
+            #[pin]
+            pub(super) fut: F,
+            pub(super) map: Option<M>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`TryEither::and_then()`] and [`TryEitherFair::and_then()`].
+        ///
+        /// [`Future`]: core::future::Future
+        pub struct AndThen<F, Fut, M> {
+            #[pin]
+            pub(super) fut: F,
+            #[pin]
+            pub(super) then: Option<Fut>,
+            pub(super) map: Option<M>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`Either::swap()`] and [`EitherFair::swap()`].
+        ///
+        /// [`Future`]: core::future::Future
+        pub struct Swap<F> {
+            #[pin]
+            pub(super) fut: F,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`TryEither::swap()`] and [`TryEitherFair::swap()`].
+        ///
+        /// [`Future`]: core::future::Future
+        pub struct TrySwap<F> {
+            #[pin]
+            pub(super) fut: F,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`FutureExt::flatten_output()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`FutureExt::flatten_output()`]: crate::FutureExt::flatten_output()
+        pub struct FlattenOutput<F, FL, FR> {
+            #[pin]
+            pub(super) fut: F,
+            #[pin]
+            pub(super) inner: Option<crate::Either<FL, FR>>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`EitherBuilder::into_future()`], [`either_biased()`] and
+        /// [`either_yielding()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`EitherBuilder::into_future()`]: crate::EitherBuilder
+        /// [`either_biased()`]: crate::either_biased()
+        /// [`either_yielding()`]: crate::either_yielding()
+        pub struct Built<L, R> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+            pub(super) priority: crate::Priority,
+            pub(super) one_per_poll: bool,
+            // The side of the future to poll on the next poll, when only one of them gets polled
+            // each time and the other one was deferred.
+            pub(super) deferred: Option<crate::Side>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`EitherBuilder::timeout()`], [`either_timeout()`] and
+        /// [`either_at()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`EitherBuilder::timeout()`]: crate::EitherBuilder::timeout()
+        /// [`either_timeout()`]: crate::either_timeout()
+        /// [`either_at()`]: crate::either_at()
+        pub struct Timeout<F, D> {
+            #[pin]
+            pub(super) fut: F,
+            #[pin]
+            pub(super) delay: D,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`either_lazy()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either_lazy()`]: crate::either_lazy()
+        pub struct EitherLazy<L, R, ML, MR> {
+            #[pin]
+            pub(super) fut: Option<Either<L, R>>,
+            pub(super) make_left: Option<ML>,
+            pub(super) make_right: Option<MR>,
+        }
+    }
+
+    #[cfg(feature = "stream")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+        /// The [`Stream`] returned by [`alternate()`].
+        ///
+        /// [`Stream`]: futures_core::Stream
+        /// [`alternate()`]: crate::alternate()
+        pub struct Alternate<L, R, ML, MR> {
+            #[pin]
+            pub(super) fut: Option<crate::Either<L, R>>,
+            pub(super) next: crate::Side,
+            pub(super) make_left: ML,
+            pub(super) make_right: MR,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`EitherExt::future()`], polling either a left or right
+        /// future.
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`EitherExt::future()`]: crate::EitherExt::future()
+        pub struct EitherFuture<L, R> {
+            #[pin]
+            pub(super) fut: crate::Either<L, R>,
+        }
+    }
+
+    /// The [`Future`] returned by [`either_biased_const()`].
+    ///
+    /// [`Future`]: core::future::Future
+    /// [`either_biased_const()`]: crate::either_biased_const()
+    pub type EitherBiased<const LEFT_FIRST: bool, L, R> =
+        EitherWith<crate::order::Biased<LEFT_FIRST>, L, R>;
+
+    pin_project! {
+        /// The [`Future`] returned by [`either_alternate()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either_alternate()`]: crate::either_alternate()
+        #[derive(Clone, Copy)]
+        pub struct EitherAlternate<L, R> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+            pub(super) order: crate::order::Alternate,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`either_with()`], [`either_by()`] and
+        /// [`either_fair_with_rng()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either_with()`]: crate::either_with()
+        /// [`either_by()`]: crate::either_by()
+        /// [`either_fair_with_rng()`]: crate::either_fair_with_rng()
+        #[derive(Clone, Copy)]
+        pub struct EitherWith<P, L, R> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+            pub(super) order: P,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`either_tracked()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either_tracked()`]: crate::either_tracked()
+        pub struct EitherTracked<'s, P, L, R> {
+            #[pin]
+            pub(super) fut: EitherWith<P, L, R>,
+            pub(super) stats: &'s core::cell::Cell<crate::RaceStats>,
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        /// The [`Future`] returned by [`either_tagged()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either_tagged()`]: crate::either_tagged()
+        pub struct EitherTagged<L, R> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+            pub(super) tags: Option<[alloc::sync::Arc<crate::WakeTag>; 2]>,
+        }
+    }
+
+    #[cfg(feature = "fair")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "fair")))]
+        /// The [`Future`] returned by [`either_weighted()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either_weighted()`]: crate::either_weighted()
+        #[derive(Clone, Copy)]
+        pub struct EitherWeighted<L, R> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+            pub(super) weight: f32,
+        }
+    }
+
+    /// The [`Future`] returned by [`Select2::select()`].
+    ///
+    /// [`Future`]: core::future::Future
+    /// [`Select2::select()`]: crate::Select2::select()
+    pub struct Select<'s, L, R> {
+        pub(super) select: &'s mut crate::Select2<L, R>,
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`either_graceful()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either_graceful()`]: crate::either_graceful()
+        pub struct EitherGraceful<L, R, CL, CR, FL, FR, OL, OR> {
+            #[pin]
+            pub(super) left: Option<L>,
+            #[pin]
+            pub(super) right: Option<R>,
+            pub(super) cancel_left: Option<CL>,
+            pub(super) cancel_right: Option<CR>,
+            // The future returned by the closure of the future which didn't complete.
+            #[pin]
+            pub(super) cancel: Option<crate::Either<FL, FR>>,
+            pub(super) out: Option<crate::Either<OL, OR>>,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`either_detach()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either_detach()`]: crate::either_detach()
+        pub struct EitherDetach<L, R, S> {
+            pub(super) fut: EitherRemainder<L, R>,
+            pub(super) spawner: S,
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    /// The [`Future`] returned by [`either_tasks()`], which aborts both tasks when dropped.
+    ///
+    /// [`Future`]: core::future::Future
+    /// [`either_tasks()`]: crate::either_tasks()
+    pub struct EitherTasks<L, R> {
+        pub(super) left: tokio::task::JoinHandle<L>,
+        pub(super) right: tokio::task::JoinHandle<R>,
+    }
+
+    #[cfg(feature = "spawned")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "spawned")))]
+    /// The [`Future`] returned by [`either_spawned()`].
+    ///
+    /// [`Future`]: core::future::Future
+    /// [`either_spawned()`]: crate::either_spawned()
+    pub struct EitherSpawned<L, R> {
+        pub(super) left: futures_channel::oneshot::Receiver<L>,
+        pub(super) right: futures_channel::oneshot::Receiver<R>,
+    }
+
+    #[cfg(feature = "spawned")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "spawned")))]
+        /// The [`Future`] handed over to the spawner by [`either_spawned()`], polling one of the
+        /// raced futures and sending its output back, unless the race has been lost or dropped.
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either_spawned()`]: crate::either_spawned()
+        pub struct Spawned<F: core::future::Future> {
+            #[pin]
+            pub(super) fut: F,
+            pub(super) tx: Option<futures_channel::oneshot::Sender<F::Output>>,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+        /// The [`Future`] returned by [`either_catch_unwind()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`either_catch_unwind()`]: crate::either_catch_unwind()
+        pub struct EitherCatchUnwind<L, R> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+        /// The [`Future`] returned by [`try_either_catch_unwind()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either_catch_unwind()`]: crate::try_either_catch_unwind()
+        pub struct TryEitherCatchUnwind<L, R> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+        }
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`try_either_supervised()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`try_either_supervised()`]: crate::try_either_supervised()
+        pub struct TryEitherSupervised<L, R, ML, MR> {
+            #[pin]
+            pub(super) left: L,
+            #[pin]
+            pub(super) right: R,
+            pub(super) make_left: ML,
+            pub(super) make_right: MR,
+            // The number of times each future has been restarted.
+            pub(super) left_restarts: usize,
+            pub(super) right_restarts: usize,
+            pub(super) max_restarts: usize,
+        }
+    }
+
+    /// The [`Future`] returned by [`either_remainder()`].
+    ///
+    /// [`Future`]: core::future::Future
+    /// [`either_remainder()`]: crate::either_remainder()
+    pub struct EitherRemainder<L, R> {
+        pub(super) futs: Option<(L, R)>,
+    }
+
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    /// The [`Future`] returned by [`FutureExt::cache()`], which can be cloned to get another handle
+    /// to the same future and output.
+    ///
+    /// [`Future`]: core::future::Future
+    /// [`FutureExt::cache()`]: crate::FutureExt::cache()
+    pub struct Cache<F, T> {
+        pub(super) state: alloc::sync::Arc<std::sync::Mutex<crate::CacheState<F, T>>>,
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    /// The [`Future`] returned by [`FutureExt::cache_local()`], which can be cloned to get another
+    /// handle to the same future and output.
+    ///
+    /// [`Future`]: core::future::Future
+    /// [`FutureExt::cache_local()`]: crate::FutureExt::cache_local()
+    pub struct CacheLocal<F, T> {
+        pub(super) state: alloc::rc::Rc<core::cell::RefCell<crate::CacheState<F, T>>>,
+    }
+
+    pin_project! {
+        /// The [`Future`] returned by [`FutureExt::after_ready()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`FutureExt::after_ready()`]: crate::FutureExt::after_ready()
+        pub struct AfterReady<F, P> {
+            #[pin]
+            pub(super) fut: Option<F>,
+            pub(super) policy: P,
+        }
+    }
+
+    #[cfg(feature = "abortable")]
+    pin_project! {
+        #[cfg_attr(docsrs, doc(cfg(feature = "abortable")))]
+        /// The [`Future`] returned by [`abortable()`].
+        ///
+        /// [`Future`]: core::future::Future
+        /// [`abortable()`]: crate::abortable()
+        pub struct Abortable<F> {
+            #[pin]
+            pub(super) fut: Option<F>,
+            pub(super) state: alloc::sync::Arc<crate::AbortState>,
+        }
+    }
+}
+
+/// The error returned by [`race_ok()`] and [`race_ok_fair()`] when both futures have failed,
+/// containing the errors they returned.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct AggregateError<EL, ER> {
+    /// The error returned by the left future.
+    pub left: EL,
+    /// The error returned by the right future.
+    pub right: ER,
+}
+
+#[cfg(feature = "abortable")]
+#[cfg_attr(docsrs, doc(cfg(feature = "abortable")))]
+/// The error returned by the future returned by [`abortable()`] when it got aborted.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Aborted;
+
+#[cfg(feature = "abortable")]
+#[cfg_attr(docsrs, doc(cfg(feature = "abortable")))]
+/// A handle allowing to abort the future returned by [`abortable()`], from any task or thread.
+#[derive(Clone)]
+pub struct AbortHandle {
+    state: Arc<AbortState>,
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+/// The payload of a panic caught by [`either_catch_unwind()`] or [`try_either_catch_unwind()`].
+pub type Panic = Box<dyn Any + Send>;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+/// The error returned by [`try_either_catch_unwind()`].
+#[derive(Debug)]
+pub enum CatchUnwindError<E> {
+    /// The first future to complete returned this error.
+    Err(E),
+    /// One of the futures panicked, with this payload, before the other one completed.
+    Panic(Either<Panic, Panic>),
+}
+
+/// The output of [`either_or_both()`], indicating which futures completed first.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum EitherOrBoth<L, R> {
+    /// Only the left future completed.
+    Left(L),
+    /// Only the right future completed.
+    Right(R),
+    /// Both futures completed.
+    Both(L, R),
+}
+
+impl<L, R> EitherOrBoth<L, R> {
+    /// Returns `true` if the value is [`Left`](Self::Left).
+    pub fn is_left(&self) -> bool {
+        matches!(self, Self::Left(_))
+    }
+
+    /// Returns `true` if the value is [`Right`](Self::Right).
+    pub fn is_right(&self) -> bool {
+        matches!(self, Self::Right(_))
+    }
+
+    /// Returns `true` if the value is [`Both`](Self::Both).
+    pub fn is_both(&self) -> bool {
+        matches!(self, Self::Both(_, _))
+    }
+
+    /// Returns the output of the left future, if it completed.
+    pub fn left(self) -> Option<L> {
+        match self {
+            Self::Left(left) | Self::Both(left, _) => Some(left),
+            Self::Right(_) => None,
+        }
+    }
+
+    /// Returns the output of the right future, if it completed.
+    pub fn right(self) -> Option<R> {
+        match self {
+            Self::Right(right) | Self::Both(_, right) => Some(right),
+            Self::Left(_) => None,
+        }
+    }
+
+    /// Returns the output of the left and right futures, if they completed.
+    pub fn left_and_right(self) -> (Option<L>, Option<R>) {
+        match self {
+            Self::Left(left) => (Some(left), None),
+            Self::Right(right) => (None, Some(right)),
+            Self::Both(left, right) => (Some(left), Some(right)),
+        }
+    }
+}
+
+/// Statistics about which futures got polled first and which ones won, as recorded by
+/// [`either_tracked()`].
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Debug)]
+pub struct RaceStats {
+    /// The number of times the left future got polled first.
+    pub left_first: usize,
+    /// The number of times the right future got polled first.
+    pub right_first: usize,
+    /// The number of races won by the left future.
+    pub left_wins: usize,
+    /// The number of races won by the right future.
+    pub right_wins: usize,
+}
+
+/// One of the two sides of a race, as returned by [`race_indexed()`].
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Side {
+    /// The left future.
+    Left,
+    /// The right future.
+    Right,
+}
+
+impl Side {
+    /// Returns the other side.
+    pub fn other(self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+/// A boxed, type-erased [`Future`] returning an [`Either`], as returned by
+/// [`futs::Either::boxed()`].
+pub type BoxEither<'a, L, R> = Pin<Box<dyn Future<Output = Either<L, R>> + Send + 'a>>;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+/// A boxed, type-erased [`Future`] returning an [`Either`], which isn't [`Send`], as returned by
+/// [`futs::Either::boxed_local()`].
+pub type LocalBoxEither<'a, L, R> = Pin<Box<dyn Future<Output = Either<L, R>> + 'a>>;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+/// The [`Future`] returned by [`dyn_either()`], racing two boxed, type-erased futures returning
+/// the same type.
+pub type DynEither<'a, T> = futs::Either<
+    Pin<Box<dyn Future<Output = T> + Send + 'a>>,
+    Pin<Box<dyn Future<Output = T> + Send + 'a>>,
+>;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+/// The [`Future`] returned by [`dyn_either_local()`], racing two boxed, type-erased futures
+/// returning the same type, which aren't [`Send`].
+pub type LocalDynEither<'a, T> =
+    futs::Either<Pin<Box<dyn Future<Output = T> + 'a>>, Pin<Box<dyn Future<Output = T> + 'a>>>;
+
+/// A wrapper around a future allowing to race it with other futures using the `|` operator.
+///
+/// `Or(left) | right` is the same as [`either(left, right)`](either()), and the `|` operator can
+/// then be used again on the returned future to race up to twelve futures, as with
+/// [`FutureExt::or()`].
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{Either, Either3, Or};
+///
+/// # future::block_on(async {
+/// #
+/// let out = (Or(future::pending::<i32>()) | async { 42 }).await;
+/// assert_eq!(out, Either::Right(42));
+///
+/// let out = (Or(future::pending::<i32>()) | future::pending::<bool>() | async { 42 }).await;
+/// assert_eq!(out, Either3::Third(42));
+/// #
+/// # });
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Or<F>(pub F);
+
+impl<F, R> BitOr<R> for Or<F>
+where
+    F: Future,
+    R: Future,
+{
+    type Output = futs::Either<F, R>;
+
+    fn bitor(self, right: R) -> Self::Output {
+        futs::Either::new(self.0, right)
+    }
+}
+
+/// The order in which the futures of an [`EitherBuilder`] get polled.
+#[derive(Copy, Clone)]
+enum Priority {
+    Biased(Side),
+    #[cfg(feature = "fair-lite")]
+    Fair,
+}
+
+/// Records whether the waker of one of the futures of [`futs::EitherTagged`] got woken up, before
+/// waking up the task's waker.
+#[cfg(feature = "alloc")]
+struct WakeTag {
+    woken: AtomicBool,
+    parent: Waker,
+}
+
+/// The state shared by the handles of a [`futs::Cache`] or [`futs::CacheLocal`].
+#[cfg(feature = "alloc")]
+struct CacheState<F, T> {
+    /// The future, taken out while a handle is polling it so that no lock or borrow is held
+    /// during the poll.
+    fut: Option<Pin<Box<F>>>,
+    out: Option<T>,
+    /// The wakers of the handles which returned [`Poll::Pending`], to wake up once the output is
+    /// available (or once a handle gets dropped, as it might have been the only one the future
+    /// would have woken up).
+    wakers: Vec<Waker>,
+}
+
+/// The generator cached by [`futs::EitherFair`] and [`futs::TryEitherFair`] to choose which future
+/// to poll first, along with the seed epoch of the thread it got seeded on (see
+/// [`Random::epoch()`]), `0` meaning that it hasn't been seeded yet.
+#[cfg(feature = "fair-lite")]
+#[derive(Clone, Copy)]
+struct FairState {
+    rng: u64,
+    epoch: u64,
+}
+
+/// Counts how many times in a row the futures of a race have woken up the task while being polled
+/// without completing, in debug builds with the `std` feature enabled, to detect when it is being
+/// woken up and polled in a loop.
+#[derive(Clone, Copy)]
+struct Spins {
+    #[cfg(all(debug_assertions, feature = "std"))]
+    polls: u32,
+}
+
+/// The state shared by an [`AbortHandle`] and its [`futs::Abortable`].
+#[cfg(feature = "abortable")]
+struct AbortState {
+    aborted: AtomicBool,
+    waker: atomic_waker::AtomicWaker,
+}
+
+// ========================================== either() ========================================== \\
+
+/// Returns a future polling two futures and returning the output of the first one to complete.
+///
+/// The returned future will always poll `left` first; for a "fair" alternative, see
+/// [`either_fair()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+/// To build it in a const context, use [`futs::Either::new()`] instead.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{either, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = either(
+///     async { 42 },
+///     async { false },
+/// ).await;
+/// assert_eq!(out, Either::Left(42));
+///
+/// let out = either(
+///     future::pending::<bool>(),
+///     async { 42 },
+/// ).await;
+/// assert_eq!(out, Either::Right(42));
+/// #
+/// # });
+/// ```
+pub fn either<L, R>(left: L, right: R) -> futs::Either<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture,
+    R: IntoFuture,
+{
+    futs::Either::new(left.into_future(), right.into_future())
+}
+
+// ======================================== either_fair() ======================================= \\
+
+#[cfg(feature = "fair-lite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+/// Returns a future polling two futures and returning the output of the first one to complete.
+///
+/// The returned future will choose which future to poll first randomly, each time it is being
+/// polled; for an "unfair" alternative, see [`either()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+/// To build it in a const context, use [`futs::EitherFair::new()`] instead.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{either_fair, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = either_fair(
+///     async { 42 },
+///     async { false },
+/// ).await;
+/// assert!(out == Either::Left(42) || out == Either::Right(false));
+///
+/// let out = either_fair(
+///     future::pending::<bool>(),
+///     async { 42 },
+/// ).await;
+/// assert_eq!(out, Either::Right(42));
+/// #
+/// # });
+/// ```
+pub fn either_fair<L, R>(left: L, right: R) -> futs::EitherFair<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture,
+    R: IntoFuture,
+{
+    futs::EitherFair::new(left.into_future(), right.into_future())
+}
+
+// =================================== either_fair_with_rng() =================================== \\
+
+#[cfg(feature = "fair")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fair")))]
+/// Returns a future polling two futures and returning the output of the first one to complete.
+///
+/// The returned future will choose which future to poll first randomly, each time it is being
+/// polled, as [`either_fair()`], but using `rng` instead of the thread-local generator. `rng` can
+/// either be a [`fastrand::Rng`] or a mutable reference to one, which allows to replay the same
+/// choices by seeding it.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{either_fair_with_rng, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let mut rng = fastrand::Rng::with_seed(42);
+///
+/// let out = either_fair_with_rng(
+///     future::pending::<bool>(),
+///     async { 42 },
+///     &mut rng,
+/// ).await;
+/// assert_eq!(out, Either::Right(42));
+///
+/// let out = either_fair_with_rng(
+///     async { 42 },
+///     async { false },
+///     rng,
+/// ).await;
+/// assert!(out == Either::Left(42) || out == Either::Right(false));
+/// #
+/// # });
+/// ```
+pub fn either_fair_with_rng<G, L, R>(
+    left: L,
+    right: R,
+    rng: G,
+) -> futs::EitherWith<G, L::IntoFuture, R::IntoFuture>
+where
+    G: BorrowMut<fastrand::Rng> + PollOrder,
+    L: IntoFuture,
+    R: IntoFuture,
+{
+    either_with(left, right, rng)
+}
+
+// ======================================= either_biased() ====================================== \\
+
+/// Returns a future polling two futures and returning the output of the first one to complete.
+///
+/// The returned future will always poll the future on `side` first, which allows to prefer the
+/// right future without swapping the arguments (and the output).
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_either::{either_biased, Either, Side};
+///
+/// # futures_lite::future::block_on(async {
+/// #
+/// let out = either_biased(Side::Right, async { 42 }, async { false }).await;
+/// assert_eq!(out, Either::Right(false));
+///
+/// let out = either_biased(Side::Left, async { 42 }, async { false }).await;
+/// assert_eq!(out, Either::Left(42));
+/// #
+/// # });
+/// ```
+pub fn either_biased<L, R>(
+    side: Side,
+    left: L,
+    right: R,
+) -> futs::Built<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture,
+    R: IntoFuture,
+{
+    EitherBuilder::new(left.into_future(), right.into_future()).biased(side).into_future()
+}
+
+/// Returns a future polling two futures and returning the output of the first one to complete.
+///
+/// The returned future will always poll `left` first if `LEFT_FIRST` is `true`, and `right` first
+/// otherwise. Contrary to [`either_biased()`], the order is known at compile time.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_either::{either_biased_const, Either};
+///
+/// # futures_lite::future::block_on(async {
+/// #
+/// let out = either_biased_const::<false, _, _>(async { 42 }, async { false }).await;
+/// assert_eq!(out, Either::Right(false));
+///
+/// let out = either_biased_const::<true, _, _>(async { 42 }, async { false }).await;
+/// assert_eq!(out, Either::Left(42));
+/// #
+/// # });
+/// ```
+pub fn either_biased_const<const LEFT_FIRST: bool, L, R>(
+    left: L,
+    right: R,
+) -> futs::EitherBiased<LEFT_FIRST, L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture,
+    R: IntoFuture,
+{
+    futs::EitherWith { left: left.into_future(), right: right.into_future(), order: order::Biased }
+}
+
+// ====================================== either_yielding() ===================================== \\
+
+/// Returns a future polling two futures and returning the output of the first one to complete.
+///
+/// Contrary to [`either()`], the returned future doesn't poll `right` right after `left` when
+/// `left` isn't ready: it instead wakes itself up and returns [`Poll::Pending`], yielding to the
+/// executor before polling `right` on the next poll. This lets latency-sensitive tasks run between
+/// the polls of two heavy futures on single-threaded executors. See
+/// [`EitherBuilder::one_per_poll()`] to combine this with other options.
+///
+/// The returned future yields once per wake-up and doesn't spin: if `right` isn't ready either, it
+/// doesn't wake itself up again and waits for `left` or `right` to wake it up, polling `left`
+/// first again when they do.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{either_yielding, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let mut fut = either_yielding(future::pending::<i32>(), future::ready(false));
+/// assert_eq!(future::poll_once(&mut fut).await, None);
+/// assert_eq!(future::poll_once(&mut fut).await, Some(Either::Right(false)));
+///
+/// let out = either_yielding(future::pending::<i32>(), async { false }).await;
+/// assert_eq!(out, Either::Right(false));
+/// #
+/// # });
+/// ```
+///
+/// [`Poll::Pending`]: core::task::Poll::Pending
+pub fn either_yielding<L, R>(left: L, right: R) -> futs::Built<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture,
+    R: IntoFuture,
+{
+    EitherBuilder::new(left.into_future(), right.into_future()).one_per_poll().into_future()
+}
+
+// ===================================== either_alternate() ===================================== \\
+
+/// Returns a future polling two futures and returning the output of the first one to complete.
+///
+/// The returned future will poll `left` first the first time it is being polled, and then
+/// alternate which future it polls first each time it is being polled. Contrary to
+/// [`either_fair()`], this doesn't rely on randomness and is thus deterministic.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{either_alternate, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = either_alternate(
+///     async { 42 },
+///     async { false },
+/// ).await;
+/// assert_eq!(out, Either::Left(42));
+///
+/// let out = either_alternate(
+///     future::pending::<bool>(),
+///     async { 42 },
+/// ).await;
+/// assert_eq!(out, Either::Right(42));
+/// #
+/// # });
+/// ```
+pub fn either_alternate<L, R>(
+    left: L,
+    right: R,
+) -> futs::EitherAlternate<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture,
+    R: IntoFuture,
+{
+    let order = order::Alternate::new();
+    futs::EitherAlternate { left: left.into_future(), right: right.into_future(), order }
+}
+
+// ====================================== either_weighted() ===================================== \\
+
+#[cfg(feature = "fair")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fair")))]
+/// Returns a future polling two futures and returning the output of the first one to complete.
+///
+/// The returned future will choose which future to poll first randomly, each time it is being
+/// polled, polling `left` first with a probability of `weight` (and `right` first otherwise).
+/// [`either_fair()`] is the same as using a `weight` of `0.5`. As for it, the choices are drawn
+/// from [`Random`], following its source and [`Random::seed()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Panics
+///
+/// Panics if `weight` isn't between `0.0` and `1.0`.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{either_weighted, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = either_weighted(
+///     async { 42 },
+///     async { false },
+///     1.0,
+/// ).await;
+/// assert_eq!(out, Either::Left(42));
+///
+/// let out = either_weighted(
+///     async { 42 },
+///     async { false },
+///     0.0,
+/// ).await;
+/// assert_eq!(out, Either::Right(false));
+/// #
+/// # });
+/// ```
+///
+/// [`Random`]: order::Random
+/// [`Random::seed()`]: order::Random::seed()
+pub fn either_weighted<L, R>(
+    left: L,
+    right: R,
+    weight: f32,
+) -> futs::EitherWeighted<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture,
+    R: IntoFuture,
+{
+    assert!((0.0..=1.0).contains(&weight), "`weight` must be between 0.0 and 1.0");
+    futs::EitherWeighted { left: left.into_future(), right: right.into_future(), weight }
+}
+
+// ======================================== either_with() ======================================= \\
+
+/// Returns a future polling two futures and returning the output of the first one to complete.
+///
+/// The returned future will ask `order` which future to poll first, each time it is being polled.
+/// See the [`order`] module for the provided policies.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::order::{Alternate, RightFirst};
+/// use futures_either::{either_with, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = either_with(async { 42 }, async { false }, RightFirst).await;
+/// assert_eq!(out, Either::Right(false));
+///
+/// let out = either_with(
+///     future::pending::<bool>(),
+///     async { 42 },
+///     Alternate::new(),
+/// ).await;
+/// assert_eq!(out, Either::Right(42));
+/// #
+/// # });
+/// ```
+pub fn either_with<P, L, R>(
+    left: L,
+    right: R,
+    order: P,
+) -> futs::EitherWith<P, L::IntoFuture, R::IntoFuture>
+where
+    P: PollOrder,
+    L: IntoFuture,
+    R: IntoFuture,
+{
+    futs::EitherWith { left: left.into_future(), right: right.into_future(), order }
+}
+
+// ========================================= either_by() ======================================== \\
+
+/// Returns a future polling two futures and returning the output of the first one to complete.
+///
+/// The returned future will call `first` to decide which future to poll first, each time it is
+/// being polled, which allows to prioritize one future or the other depending on runtime state.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{either_by, Either, Side};
+///
+/// # future::block_on(async {
+/// #
+/// let backlog = 12;
+/// let out = either_by(
+///     async { "work" },
+///     async { "shutdown" },
+///     || if backlog > 10 { Side::Left } else { Side::Right },
+/// ).await;
+/// assert_eq!(out, Either::Left("work"));
+/// #
+/// # });
+/// ```
+pub fn either_by<L, R, F>(
+    left: L,
+    right: R,
+    first: F,
+) -> futs::EitherWith<order::FromFn<F>, L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture,
+    R: IntoFuture,
+    F: FnMut() -> Side,
+{
+    either_with(left, right, order::from_fn(first))
+}
+
+// ====================================== either_tracked() ====================================== \\
+
+/// Returns a future polling two futures and returning the output of the first one to complete, as
+/// [`either_with()`], while recording which future `order` chose to poll first and which one won
+/// in `stats`.
+///
+/// `stats` can be shared by multiple races, to verify how fair a policy is in practice.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use core::cell::Cell;
+/// use futures_lite::future;
+/// use futures_either::order::Alternate;
+/// use futures_either::{either_tracked, RaceStats};
+///
+/// # future::block_on(async {
+/// #
+/// let stats = Cell::new(RaceStats::default());
+/// for _ in 0..4 {
+///     either_tracked(async { 42 }, async { false }, Alternate::new(), &stats).await;
+/// }
+///
+/// let stats = stats.get();
+/// assert_eq!(stats.left_first, 4);
+/// assert_eq!(stats.left_wins, 4);
+/// assert_eq!(stats.right_wins, 0);
+/// #
+/// # });
+/// ```
+pub fn either_tracked<'s, P, L, R>(
+    left: L,
+    right: R,
+    order: P,
+    stats: &'s Cell<RaceStats>,
+) -> futs::EitherTracked<'s, P, L::IntoFuture, R::IntoFuture>
+where
+    P: PollOrder,
+    L: IntoFuture,
+    R: IntoFuture,
+{
+    futs::EitherTracked { fut: either_with(left, right, order), stats }
+}
+
+// ======================================= either_tagged() ====================================== \\
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+/// Returns a future polling two futures and returning the output of the first one to complete.
+///
+/// Contrary to [`either()`], the returned future gives each future its own waker, and only polls
+/// again the futures which woke it up, which avoids polling a future whose poll is expensive when
+/// only the other one can make progress. Both futures get polled when first polled, when the
+/// task's waker changes and when it is woken up by something else.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use core::cell::Cell;
+/// use core::task::Poll;
+/// use futures_lite::future;
+/// use futures_either::{either_tagged, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let polls = Cell::new(0);
+/// let out = either_tagged(
+///     future::poll_fn(|_| {
+///         polls.set(polls.get() + 1);
+///         Poll::<i32>::Pending
+///     }),
+///     async {
+///         future::yield_now().await;
+///         false
+///     },
+/// ).await;
+///
+/// assert_eq!(out, Either::Right(false));
+/// assert_eq!(polls.get(), 1);
+/// #
+/// # });
+/// ```
+pub fn either_tagged<L, R>(left: L, right: R) -> futs::EitherTagged<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture,
+    R: IntoFuture,
+{
+    futs::EitherTagged { left: left.into_future(), right: right.into_future(), tags: None }
+}
+
+// ======================================== either_mut() ======================================== \\
+
+/// Returns a future polling two futures by mutable reference and returning the output of the first
+/// one to complete, as [`either()`] does.
+///
+/// As the futures are only borrowed, the one which didn't complete doesn't get dropped and can
+/// keep being polled (or awaited) afterwards, e.g. in the next iteration of a loop, without losing
+/// its progress. Both futures must implement [`Unpin`]; for futures which don't, see
+/// [`either_pin()`].
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{either_mut, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let mut slow = Box::pin(async {
+///     future::yield_now().await;
+///     42
+/// });
+/// let mut fast = future::ready(false);
+///
+/// let out = either_mut(&mut slow, &mut fast).await;
+/// assert_eq!(out, Either::Right(false));
+/// assert_eq!(slow.await, 42);
+/// #
+/// # });
+/// ```
+pub fn either_mut<'a, L, R>(left: &'a mut L, right: &'a mut R) -> futs::Either<&'a mut L, &'a mut R>
+where
+    L: Future + Unpin + ?Sized,
+    R: Future + Unpin + ?Sized,
+{
+    futs::Either::new(left, right)
+}
+
+// ======================================== either_pin() ======================================== \\
+
+/// Returns a future polling two pinned futures and returning the output of the first one to
+/// complete, as [`either_mut()`] does for futures implementing [`Unpin`].
+///
+/// This allows to race futures pinned with [`pin!`] (or boxed) without taking ownership of them,
+/// so that the one which didn't complete can keep being polled afterwards.
+///
+/// ## Example
+///
+/// ```rust
+/// use core::pin::pin;
+///
+/// use futures_lite::future;
+/// use futures_either::{either_pin, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let mut ticks = 0;
+/// let mut done = pin!(async {
+///     for _ in 0..3 {
+///         future::yield_now().await;
+///     }
+///     42
+/// });
+///
+/// let out = loop {
+///     let mut tick = pin!(async {});
+///     match either_pin(done.as_mut(), tick.as_mut()).await {
+///         Either::Left(out) => break out,
+///         Either::Right(()) => ticks += 1,
+///     }
+/// };
+///
+/// assert_eq!(out, 42);
+/// assert_eq!(ticks, 3);
+/// #
+/// # });
+/// ```
+///
+/// [`pin!`]: core::pin::pin
+pub fn either_pin<'a, L, R>(
+    left: Pin<&'a mut L>,
+    right: Pin<&'a mut R>,
+) -> futs::Either<Pin<&'a mut L>, Pin<&'a mut R>>
+where
+    L: Future + ?Sized,
+    R: Future + ?Sized,
+{
+    futs::Either::new(left, right)
+}
+
+// ===================================== either_remainder() ===================================== \\
+
+/// Returns a future polling two futures and returning the output of the first one to complete,
+/// along with the other future.
+///
+/// Contrary to [`either()`], the future which didn't complete doesn't get dropped, and can keep
+/// being polled (or awaited) afterwards. Both futures must implement [`Unpin`] so that they can be
+/// moved out of the returned future; to race futures which don't, see [`either_pin()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{either_remainder, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let slow = Box::pin(async {
+///     future::yield_now().await;
+///     42
+/// });
+///
+/// match either_remainder(slow, future::ready(false)).await {
+///     Either::Left(_) => unreachable!(),
+///     Either::Right((slow, out)) => {
+///         assert!(!out);
+///         assert_eq!(slow.await, 42);
+///     }
+/// }
+/// #
+/// # });
+/// ```
+pub fn either_remainder<L, R>(
+    left: L,
+    right: R,
+) -> futs::EitherRemainder<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture,
+    R: IntoFuture,
+    L::IntoFuture: Unpin,
+    R::IntoFuture: Unpin,
+{
+    futs::EitherRemainder { futs: Some((left.into_future(), right.into_future())) }
+}
+
+// ====================================== either_graceful() ===================================== \\
+
+/// Returns a future polling two futures and, once one of them completes, waiting for the other one
+/// to be cancelled gracefully before returning the output of the first one.
+///
+/// When `left` completes first, `cancel_right` gets called and the future it returns is awaited
+/// (and vice versa), e.g. to signal the other future to stop and wait for it to clean up. Until
+/// that future completes, the other future keeps getting polled, so that it can react to the
+/// signal, and it gets dropped afterwards.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use core::cell::Cell;
+///
+/// use futures_lite::future;
+/// use futures_either::{either_graceful, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let cancelled = Cell::new(false);
+/// let out = either_graceful(
+///     future::pending::<i32>(),
+///     async { false },
+///     || async { cancelled.set(true) },
+///     || async {},
+/// ).await;
+///
+/// assert_eq!(out, Either::Right(false));
+/// assert!(cancelled.get());
+/// #
+/// # });
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn either_graceful<L, R, CL, CR, FL, FR>(
+    left: L,
+    right: R,
+    cancel_left: CL,
+    cancel_right: CR,
+) -> futs::EitherGraceful<L::IntoFuture, R::IntoFuture, CL, CR, FL, FR, L::Output, R::Output>
+where
+    L: IntoFuture,
+    R: IntoFuture,
+    CL: FnOnce() -> FL,
+    CR: FnOnce() -> FR,
+    FL: Future,
+    FR: Future,
+{
+    futs::EitherGraceful {
+        left: Some(left.into_future()),
+        right: Some(right.into_future()),
+        cancel_left: Some(cancel_left),
+        cancel_right: Some(cancel_right),
+        cancel: None,
+        out: None,
+    }
+}
+
+// ======================================= either_detach() ====================================== \\
+
+/// Returns a future polling two futures and returning the output of the first one to complete,
+/// after handing the other one over to `spawner`.
+///
+/// Contrary to [`either()`], the future which didn't complete doesn't get dropped, and its work
+/// isn't lost: it keeps getting polled by the executor `spawner` hands it to (see [`spawn`]). As
+/// for [`either_remainder()`], both futures must implement [`Unpin`] so that they can be moved out
+/// of the returned future.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use core::future::Future;
+/// use core::pin::Pin;
+///
+/// use futures_lite::future;
+/// use futures_either::{either_detach, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let left: Pin<Box<dyn Future<Output = i32>>> = Box::pin(future::pending());
+/// let right: Pin<Box<dyn Future<Output = i32>>> = Box::pin(async { 42 });
+///
+/// let mut detached = Vec::new();
+/// let out = either_detach(left, right, |fut| detached.push(fut)).await;
+///
+/// assert_eq!(out, Either::Right(42));
+/// assert_eq!(detached.len(), 1);
+/// #
+/// # });
+/// ```
+///
+/// [`spawn`]: crate::spawn
+pub fn either_detach<L, R, S>(
+    left: L,
+    right: R,
+    spawner: S,
+) -> futs::EitherDetach<L::IntoFuture, R::IntoFuture, S>
+where
+    L: IntoFuture,
+    R: IntoFuture,
+    L::IntoFuture: Unpin,
+    R::IntoFuture: Unpin,
+    S: Spawn<L::IntoFuture> + Spawn<R::IntoFuture>,
+{
+    futs::EitherDetach { fut: either_remainder(left, right), spawner }
+}
+
+// ======================================= either_tasks() ======================================= \\
+
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+/// Returns a future waiting for two Tokio tasks and returning the output of the first one to
+/// complete, after aborting the other one.
+///
+/// If the first task to complete panicked or got cancelled, the returned future returns the
+/// corresponding [`JoinError`] (and the other task still gets aborted). Both tasks also get aborted
+/// if the returned future is dropped before completing, instead of being left running in the
+/// background as when dropping their [`JoinHandle`]s.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{either_tasks, Either};
+///
+/// let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// rt.block_on(async {
+///     let left = tokio::spawn(future::pending::<i32>());
+///     let right = tokio::spawn(async { false });
+///
+///     let out = either_tasks(left, right).await;
+///     assert!(matches!(out, Ok(Either::Right(false))));
+/// });
+/// ```
+///
+/// [`JoinError`]: tokio::task::JoinError
+/// [`JoinHandle`]: tokio::task::JoinHandle
+pub fn either_tasks<L, R>(
+    left: tokio::task::JoinHandle<L>,
+    right: tokio::task::JoinHandle<R>,
+) -> futs::EitherTasks<L, R> {
+    futs::EitherTasks { left, right }
+}
+
+// ====================================== either_spawned() ====================================== \\
+
+#[cfg(feature = "spawned")]
+#[cfg_attr(docsrs, doc(cfg(feature = "spawned")))]
+/// Hands two futures over to `spawner` right away, so that they can run concurrently on separate
+/// tasks, and returns a future returning the output of the first one to complete.
+///
+/// Contrary to [`either()`], which polls both futures on the current task, this allows futures
+/// doing heavy work to run in parallel on a multi-threaded executor. Once one of them completes
+/// (or once the returned future gets dropped), the task of the other one stops at its next poll,
+/// dropping the future without polling it any further.
+///
+/// The returned future returns [`Canceled`] if the first task to stop got dropped by the executor
+/// before its future completed (e.g. because it panicked).
+///
+/// Neither the futures nor their outputs need to implement [`Send`] if `spawner` doesn't require
+/// it, e.g. to run them on separate tasks of the current thread with `spawn::TokioLocal` (with the
+/// `tokio` feature) or with a closure spawning them on a local executor.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use core::future::Future;
+/// use core::pin::Pin;
+/// use std::thread;
+///
+/// use futures_lite::future;
+/// use futures_either::{either_spawned, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let left: Pin<Box<dyn Future<Output = i32> + Send>> = Box::pin(future::pending());
+/// let right: Pin<Box<dyn Future<Output = i32> + Send>> = Box::pin(async { 42 });
+///
+/// let out = either_spawned(left, right, |task| {
+///     thread::spawn(move || future::block_on(task));
+/// }).await;
+/// assert_eq!(out, Ok(Either::Right(42)));
+/// #
+/// # });
+/// ```
+///
+/// [`Canceled`]: futures_channel::oneshot::Canceled
+pub fn either_spawned<L, R, S>(
+    left: L,
+    right: R,
+    mut spawner: S,
+) -> futs::EitherSpawned<L::Output, R::Output>
+where
+    L: IntoFuture,
+    R: IntoFuture,
+    S: Spawn<futs::Spawned<L::IntoFuture>> + Spawn<futs::Spawned<R::IntoFuture>>,
+{
+    let (left_tx, left_rx) = futures_channel::oneshot::channel();
+    let (right_tx, right_rx) = futures_channel::oneshot::channel();
+
+    spawner.spawn(futs::Spawned { fut: left.into_future(), tx: Some(left_tx) });
+    spawner.spawn(futs::Spawned { fut: right.into_future(), tx: Some(right_tx) });
+
+    futs::EitherSpawned { left: left_rx, right: right_rx }
+}
+
+// ====================== either_catch_unwind() & try_either_catch_unwind() ===================== \\
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+/// Returns a future polling two futures and returning the output of the first one to complete, or
+/// the payload of the first one to panic, tagged with its side.
+///
+/// Contrary to [`either()`], a panic of one of the futures doesn't unwind through the task polling
+/// the returned future. As with [`catch_unwind()`], both futures must implement [`UnwindSafe`],
+/// which can be asserted by wrapping them with [`AssertUnwindSafe`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{either_catch_unwind, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = either_catch_unwind(
+///     future::pending::<i32>(),
+///     future::poll_fn(|_| -> std::task::Poll<bool> { panic!("oops") }),
+/// ).await;
+///
+/// match out {
+///     Err(Either::Right(panic)) => assert_eq!(panic.downcast_ref(), Some(&"oops")),
+///     _ => unreachable!(),
+/// }
+/// #
+/// # });
+/// ```
+///
+/// [`catch_unwind()`]: std::panic::catch_unwind()
+/// [`UnwindSafe`]: std::panic::UnwindSafe
+/// [`AssertUnwindSafe`]: std::panic::AssertUnwindSafe
+pub fn either_catch_unwind<L, R>(
+    left: L,
+    right: R,
+) -> futs::EitherCatchUnwind<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture,
+    R: IntoFuture,
+    L::IntoFuture: UnwindSafe,
+    R::IntoFuture: UnwindSafe,
+{
+    futs::EitherCatchUnwind { left: left.into_future(), right: right.into_future() }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+/// Returns a future polling two futures and returning a result with the output or error returned
+/// by the first one to complete, or the payload of the first one to panic, tagged with its side.
+///
+/// This is [`try_either()`], catching panics as [`either_catch_unwind()`] does.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{try_either_catch_unwind, CatchUnwindError, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = try_either_catch_unwind(
+///     future::pending::<Result<i32, i32>>(),
+///     future::ready(Result::<bool, i32>::Err(42)),
+/// ).await;
+/// assert!(matches!(out, Err(CatchUnwindError::Err(42))));
+///
+/// let out = try_either_catch_unwind(
+///     future::poll_fn(|_| -> std::task::Poll<Result<i32, i32>> { panic!("oops") }),
+///     future::pending::<Result<bool, i32>>(),
+/// ).await;
+/// assert!(matches!(out, Err(CatchUnwindError::Panic(Either::Left(_)))));
+/// #
+/// # });
+/// ```
+pub fn try_either_catch_unwind<OL, OR, E, L, R>(
+    left: L,
+    right: R,
+) -> futs::TryEitherCatchUnwind<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = Result<OL, E>>,
+    R: IntoFuture<Output = Result<OR, E>>,
+    L::IntoFuture: UnwindSafe,
+    R::IntoFuture: UnwindSafe,
+{
+    futs::TryEitherCatchUnwind { left: left.into_future(), right: right.into_future() }
+}
+
+// =================================== try_either_supervised() ================================== \\
+
+/// Returns a future creating two futures with `make_left` and `make_right`, polling them and
+/// returning a result with the output returned by the first one to complete successfully,
+/// restarting a future which returns an error up to `max_restarts` times.
+///
+/// When one of the futures returns an error, it gets replaced by a new one created by its factory
+/// (which gets passed the number of times it has been restarted), while the other one keeps its
+/// progress. Once a future has returned an error after having been restarted `max_restarts` times,
+/// the returned future returns that error. A backoff can be implemented by making the factory
+/// return a future waiting for a delay before starting when the number it gets passed isn't `0`.
+///
+/// The factories get called right away to create the first futures.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{try_either_supervised, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = try_either_supervised(
+///     |restarts| future::ready(if restarts < 2 { Err("flaky") } else { Ok(42) }),
+///     |_| future::pending::<Result<bool, &str>>(),
+///     3,
+/// ).await;
+/// assert_eq!(out, Ok(Either::Left(42)));
+///
+/// let out = try_either_supervised(
+///     |_| future::ready(Result::<i32, &str>::Err("broken")),
+///     |_| future::pending::<Result<bool, &str>>(),
+///     3,
+/// ).await;
+/// assert_eq!(out, Err("broken"));
+/// #
+/// # });
+/// ```
+pub fn try_either_supervised<OL, OR, E, L, R, ML, MR>(
+    mut make_left: ML,
+    mut make_right: MR,
+    max_restarts: usize,
+) -> futs::TryEitherSupervised<L, R, ML, MR>
+where
+    L: Future<Output = Result<OL, E>>,
+    R: Future<Output = Result<OR, E>>,
+    ML: FnMut(usize) -> L,
+    MR: FnMut(usize) -> R,
+{
+    futs::TryEitherSupervised {
+        left: make_left(0),
+        right: make_right(0),
+        make_left,
+        make_right,
+        left_restarts: 0,
+        right_restarts: 0,
+        max_restarts,
+    }
+}
+
+// =============================== either_timeout() & either_at() =============================== \\
+
+/// Returns a future polling `fut` and returning its output, or [`Elapsed`] if `duration` elapses
+/// before it completes.
+///
+/// The delay gets created right away by `timer` (see [`time`]), which allows to use this with any
+/// runtime. `fut` gets polled before the delay, and can be anything implementing [`IntoFuture`].
+///
+/// ## Example
+///
+/// ```rust
+/// use core::time::Duration;
+///
+/// use futures_lite::future;
+/// use futures_either::either_timeout;
+/// use futures_either::time::{Elapsed, Timer};
+///
+/// struct Never;
+///
+/// impl Timer for Never {
+///     type Instant = ();
+///     type Delay = future::Pending<()>;
+///
+///     fn delay(&mut self, _: Duration) -> Self::Delay {
+///         future::pending()
+///     }
+///
+///     fn delay_until(&mut self, _: ()) -> Self::Delay {
+///         future::pending()
+///     }
+/// }
+///
+/// # future::block_on(async {
+/// #
+/// let out = either_timeout(async { 42 }, Duration::from_secs(1), Never).await;
+/// assert_eq!(out, Ok(42));
+/// #
+/// # });
+/// ```
+///
+/// [`Elapsed`]: time::Elapsed
+pub fn either_timeout<F, T>(
+    fut: F,
+    duration: Duration,
+    mut timer: T,
+) -> futs::Timeout<F::IntoFuture, T::Delay>
+where
+    F: IntoFuture,
+    T: Timer,
+{
+    futs::Timeout { fut: fut.into_future(), delay: timer.delay(duration) }
+}
+
+/// Returns a future polling `fut` and returning its output, or [`Elapsed`] if `deadline` is
+/// reached before it completes.
+///
+/// This is [`either_timeout()`], with a deadline instead of a duration.
+///
+/// [`Elapsed`]: time::Elapsed
+pub fn either_at<F, T>(
+    fut: F,
+    deadline: T::Instant,
+    mut timer: T,
+) -> futs::Timeout<F::IntoFuture, T::Delay>
+where
+    F: IntoFuture,
+    T: Timer,
+{
+    futs::Timeout { fut: fut.into_future(), delay: timer.delay_until(deadline) }
+}
+
+// ======================================== either_now() ======================================== \\
+
+/// Polls two futures once, without waiting, returning the output of the first one to be ready, or
+/// `None` if neither is.
+///
+/// `left` gets polled first, and `right` only if `left` isn't ready. They get polled with a waker
+/// doing nothing, and get dropped before this returns, so this is mostly useful for fast paths
+/// where the output might already be available. Passing `&mut fut` (for a future implementing
+/// [`Unpin`]) instead allows to keep polling (or awaiting) it afterwards.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`].
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{either_now, Either};
+///
+/// let out = either_now(future::pending::<i32>(), async { false });
+/// assert_eq!(out, Some(Either::Right(false)));
+///
+/// let out = either_now(future::pending::<i32>(), future::pending::<bool>());
+/// assert_eq!(out, None);
+/// ```
+pub fn either_now<L, R>(left: L, right: R) -> Option<Either<L::Output, R::Output>>
+where
+    L: IntoFuture,
+    R: IntoFuture,
+{
+    let left = pin!(left.into_future());
+    let right = pin!(right.into_future());
+
+    let mut ctx = Context::from_waker(Waker::noop());
+    match poll_in_order(&mut ctx, Side::Left, left, right) {
+        Poll::Ready(out) => Some(out),
+        Poll::Pending => None,
+    }
+}
+
+// ======================================== poll_either() ======================================= \\
+
+/// Polls two pinned futures once, returning the output of the first one to be ready, like
+/// [`either()`] does.
+///
+/// This allows hand-written [`Future`]s to race two of their fields, without wrapping them in the
+/// future returned by [`either()`]. `left` gets polled first, and `right` only if `left` isn't
+/// ready.
+///
+/// ## Example
+///
+/// ```rust
+/// use core::future::Future;
+/// use core::pin::Pin;
+/// use core::task::{Context, Poll};
+///
+/// use futures_lite::future::{self, Pending, Ready};
+/// use futures_either::{poll_either, Either};
+///
+/// struct Lookup {
+///     cache: Pending<i32>,
+///     db: Ready<i32>,
+/// }
+///
+/// impl Future for Lookup {
+///     type Output = i32;
+///
+///     fn poll(mut self: Pin<&mut Self>, ctx: &mut Context) -> Poll<i32> {
+///         let this = &mut *self;
+///         poll_either(ctx, Pin::new(&mut this.cache), Pin::new(&mut this.db))
+///             .map(Either::into_inner)
+///     }
+/// }
+///
+/// # future::block_on(async {
+/// #
+/// let out = Lookup { cache: future::pending(), db: future::ready(42) }.await;
+/// assert_eq!(out, 42);
+/// #
+/// # });
+/// ```
+///
+/// [`Future`]: core::future::Future
+pub fn poll_either<L, R>(
+    ctx: &mut Context,
+    left: Pin<&mut L>,
+    right: Pin<&mut R>,
+) -> Poll<Either<L::Output, R::Output>>
+where
+    L: Future,
+    R: Future,
+{
+    poll_in_order(ctx, Side::Left, left, right)
+}
+
+#[cfg(feature = "fair-lite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+/// Polls two pinned futures once, returning the output of the first one to be ready, like
+/// [`either_fair()`] does.
+///
+/// This is the same as [`poll_either()`], except that which future gets polled first is chosen
+/// randomly.
+///
+/// ## Example
+///
+/// ```rust
+/// use core::pin::pin;
+///
+/// use futures_lite::future;
+/// use futures_either::{poll_either_fair, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let mut left = pin!(future::pending::<i32>());
+/// let mut right = pin!(async { false });
+///
+/// let out = future::poll_fn(|ctx| poll_either_fair(ctx, left.as_mut(), right.as_mut())).await;
+/// assert_eq!(out, Either::Right(false));
+/// #
+/// # });
+/// ```
+pub fn poll_either_fair<L, R>(
+    ctx: &mut Context,
+    left: Pin<&mut L>,
+    right: Pin<&mut R>,
+) -> Poll<Either<L::Output, R::Output>>
+where
+    L: Future,
+    R: Future,
+{
+    poll_in_order(ctx, Random.first(), left, right)
+}
+
+/// Polls two pinned futures once, returning the output of the first one to be ready, like
+/// [`try_either()`] does.
+///
+/// This is the same as [`poll_either()`], except that it returns an error if the first future to
+/// be ready failed.
+///
+/// ## Example
+///
+/// ```rust
+/// use core::pin::pin;
+///
+/// use futures_lite::future;
+/// use futures_either::{poll_try_either, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let mut left = pin!(future::pending::<Result<i32, ()>>());
+/// let mut right = pin!(async { Err::<bool, _>(()) });
+///
+/// let out = future::poll_fn(|ctx| poll_try_either(ctx, left.as_mut(), right.as_mut())).await;
+/// assert_eq!(out, Err(()));
+/// #
+/// # });
+/// ```
+pub fn poll_try_either<OL, OR, E, L, R>(
+    ctx: &mut Context,
+    left: Pin<&mut L>,
+    right: Pin<&mut R>,
+) -> Poll<Result<Either<OL, OR>, E>>
+where
+    L: Future<Output = Result<OL, E>>,
+    R: Future<Output = Result<OR, E>>,
+{
+    poll_try_in_order(ctx, Side::Left, left, right)
+}
+
+#[cfg(feature = "fair-lite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+/// Polls two pinned futures once, returning the output of the first one to be ready, like
+/// [`try_either_fair()`] does.
+///
+/// This is the same as [`poll_try_either()`], except that which future gets polled first is
+/// chosen randomly.
+///
+/// ## Example
+///
+/// ```rust
+/// use core::pin::pin;
+///
+/// use futures_lite::future;
+/// use futures_either::{poll_try_either_fair, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let mut left = pin!(future::pending::<Result<i32, ()>>());
+/// let mut right = pin!(async { Ok::<_, ()>(false) });
+///
+/// let out = future::poll_fn(|ctx| {
+///     poll_try_either_fair(ctx, left.as_mut(), right.as_mut())
+/// }).await;
+/// assert_eq!(out, Ok(Either::Right(false)));
+/// #
+/// # });
+/// ```
+pub fn poll_try_either_fair<OL, OR, E, L, R>(
+    ctx: &mut Context,
+    left: Pin<&mut L>,
+    right: Pin<&mut R>,
+) -> Poll<Result<Either<OL, OR>, E>>
+where
+    L: Future<Output = Result<OL, E>>,
+    R: Future<Output = Result<OR, E>>,
+{
+    poll_try_in_order(ctx, Random.first(), left, right)
+}
+
+// ======================================== try_either() ======================================== \\
+
+/// Returns a future polling two futures and returning a result with the output or error returned
+/// by the first one to complete.
+///
+/// The returned future will always poll `left` first; for a "fair" alternative, see
+/// [`try_either_fair()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+/// To build it in a const context, use [`futs::TryEither::new()`] instead.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{try_either, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = try_either(
+///     async { Ok(42) },
+///     async { Result::<bool, bool>::Err(false) },
+/// ).await;
+/// assert_eq!(out, Ok(Either::Left(42)));
+///
+/// let out = try_either(
+///     future::pending::<Result<bool, i32>>(),
+///     async { Result::<i32, i32>::Err(42) },
+/// ).await;
+/// assert_eq!(out, Err(42));
+/// #
+/// # });
+/// ```
+pub fn try_either<OL, OR, E, L, R>(
+    left: L,
+    right: R,
+) -> futs::TryEither<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = Result<OL, E>>,
+    R: IntoFuture<Output = Result<OR, E>>,
+{
+    futs::TryEither::new(left.into_future(), right.into_future())
+}
+
+// ====================================== try_either_fair() ===================================== \\
+
+#[cfg(feature = "fair-lite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+/// Returns a future polling two futures and returning a result with the ouput or error returned by
+/// the first one to complete.
+///
+/// The returned future will choose which future to poll first randomly, each time it is being
+/// polled; for an "unfair" alternative, see [`try_either()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+/// To build it in a const context, use [`futs::TryEitherFair::new()`] instead.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{try_either_fair, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = try_either_fair(
+///     async { Ok(42) },
+///     async { Result::<bool, bool>::Err(false) },
+/// ).await;
+/// assert!(out == Ok(Either::Left(42)) || out == Err(false));
+///
+/// let out = try_either_fair(
+///     future::pending::<Result<bool, i32>>(),
+///     async { Result::<i32, i32>::Err(42) },
+/// ).await;
+/// assert_eq!(out, Err(42));
+/// #
+/// # });
+/// ```
+pub fn try_either_fair<OL, OR, E, L, R>(
+    left: L,
+    right: R,
+) -> futs::TryEitherFair<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = Result<OL, E>>,
+    R: IntoFuture<Output = Result<OR, E>>,
+{
+    futs::TryEitherFair::new(left.into_future(), right.into_future())
+}
+
+// =========================================== race() =========================================== \\
+
+/// Returns a future polling two futures with the same output type and returning the output of the
+/// first one to complete.
+///
+/// The returned future will always poll `left` first; for a "fair" alternative, see
+/// [`race_fair()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::race;
+///
+/// # future::block_on(async {
+/// #
+/// let out = race(
+///     async { 42 },
+///     async { 24 },
+/// ).await;
+/// assert_eq!(out, 42);
+///
+/// let out = race(
+///     future::pending::<i32>(),
+///     async { 42 },
+/// ).await;
+/// assert_eq!(out, 42);
+/// #
+/// # });
+/// ```
+pub fn race<T, L, R>(left: L, right: R) -> futs::Race<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = T>,
+    R: IntoFuture<Output = T>,
+{
+    futs::Race::new(left.into_future(), right.into_future())
+}
+
+// ========================================= race_fair() ======================================== \\
+
+#[cfg(feature = "fair-lite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+/// Returns a future polling two futures with the same output type and returning the output of the
+/// first one to complete.
+///
+/// The returned future will choose which future to poll first randomly, each time it is being
+/// polled; for an "unfair" alternative, see [`race()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::race_fair;
+///
+/// # future::block_on(async {
+/// #
+/// let out = race_fair(
+///     async { 42 },
+///     async { 24 },
+/// ).await;
+/// assert!(out == 42 || out == 24);
+///
+/// let out = race_fair(
+///     future::pending::<i32>(),
+///     async { 42 },
+/// ).await;
+/// assert_eq!(out, 42);
+/// #
+/// # });
+/// ```
+pub fn race_fair<T, L, R>(left: L, right: R) -> futs::RaceFair<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = T>,
+    R: IntoFuture<Output = T>,
+{
+    futs::RaceFair::new(left.into_future(), right.into_future())
+}
+
+// ======================================= race_indexed() ======================================= \\
+
+/// Returns a future polling two futures with the same output type and returning the output of the
+/// first one to complete, along with the [`Side`] of the future it is the output of.
+///
+/// The returned future will always poll `left` first; for a "fair" alternative, see
+/// [`race_indexed_fair()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{race_indexed, Side};
+///
+/// # future::block_on(async {
+/// #
+/// let out = race_indexed(
+///     future::pending::<i32>(),
+///     async { 42 },
+/// ).await;
+/// assert_eq!(out, (Side::Right, 42));
+/// #
+/// # });
+/// ```
+pub fn race_indexed<T, L, R>(left: L, right: R) -> futs::RaceIndexed<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = T>,
+    R: IntoFuture<Output = T>,
+{
+    futs::RaceIndexed::new(left.into_future(), right.into_future())
+}
+
+// ===================================== race_indexed_fair() ==================================== \\
+
+#[cfg(feature = "fair-lite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+/// Returns a future polling two futures with the same output type and returning the output of the
+/// first one to complete, along with the [`Side`] of the future it is the output of.
+///
+/// The returned future will choose which future to poll first randomly, each time it is being
+/// polled; for an "unfair" alternative, see [`race_indexed()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{race_indexed_fair, Side};
+///
+/// # future::block_on(async {
+/// #
+/// let out = race_indexed_fair(
+///     async { 42 },
+///     future::pending::<i32>(),
+/// ).await;
+/// assert_eq!(out, (Side::Left, 42));
+/// #
+/// # });
+/// ```
+pub fn race_indexed_fair<T, L, R>(
+    left: L,
+    right: R,
+) -> futs::RaceIndexedFair<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = T>,
+    R: IntoFuture<Output = T>,
+{
+    futs::RaceIndexedFair::new(left.into_future(), right.into_future())
+}
+
+// ========================================= try_race() ========================================= \\
+
+/// Returns a future polling two futures with the same output type and returning a result with the
+/// output or error returned by the first one to complete.
+///
+/// The returned future will always poll `left` first; for a "fair" alternative, see
+/// [`try_race_fair()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::try_race;
+///
+/// # future::block_on(async {
+/// #
+/// let out = try_race(
+///     async { Ok(42) },
+///     async { Result::<i32, bool>::Err(false) },
+/// ).await;
+/// assert_eq!(out, Ok(42));
+///
+/// let out = try_race(
+///     future::pending::<Result<bool, i32>>(),
+///     async { Err(42) },
+/// ).await;
+/// assert_eq!(out, Err(42));
+/// #
+/// # });
+/// ```
+pub fn try_race<T, E, L, R>(left: L, right: R) -> futs::TryRace<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = Result<T, E>>,
+    R: IntoFuture<Output = Result<T, E>>,
+{
+    futs::TryRace::new(left.into_future(), right.into_future())
+}
+
+// ======================================= try_race_fair() ====================================== \\
+
+#[cfg(feature = "fair-lite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+/// Returns a future polling two futures with the same output type and returning a result with the
+/// output or error returned by the first one to complete.
+///
+/// The returned future will choose which future to poll first randomly, each time it is being
+/// polled; for an "unfair" alternative, see [`try_race()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::try_race_fair;
+///
+/// # future::block_on(async {
+/// #
+/// let out = try_race_fair(
+///     async { Ok(42) },
+///     async { Result::<i32, bool>::Err(false) },
+/// ).await;
+/// assert!(out == Ok(42) || out == Err(false));
+///
+/// let out = try_race_fair(
+///     future::pending::<Result<bool, i32>>(),
+///     async { Err(42) },
+/// ).await;
+/// assert_eq!(out, Err(42));
+/// #
+/// # });
+/// ```
+pub fn try_race_fair<T, E, L, R>(
+    left: L,
+    right: R,
+) -> futs::TryRaceFair<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = Result<T, E>>,
+    R: IntoFuture<Output = Result<T, E>>,
+{
+    futs::TryRaceFair::new(left.into_future(), right.into_future())
+}
+
+// ========================================== race_ok() ========================================= \\
+
+/// Returns a future polling two futures and returning the output of the first one to succeed, or
+/// the errors returned by both if neither succeeds.
+///
+/// Contrary to [`try_either()`], the returned future will keep polling the other future if one of
+/// them fails. It will always poll `left` first; for a "fair" alternative, see [`race_ok_fair()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{race_ok, AggregateError, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = race_ok(
+///     async { Result::<bool, i32>::Err(42) },
+///     async { Result::<i32, bool>::Ok(42) },
+/// ).await;
+/// assert_eq!(out, Ok(Either::Right(42)));
+///
+/// let out = race_ok(
+///     async { Result::<bool, i32>::Err(42) },
+///     async { Result::<i32, bool>::Err(false) },
+/// ).await;
+/// assert_eq!(out, Err(AggregateError { left: 42, right: false }));
+/// #
+/// # });
+/// ```
+pub fn race_ok<OL, OR, EL, ER, L, R>(
+    left: L,
+    right: R,
+) -> futs::RaceOk<L::IntoFuture, R::IntoFuture, EL, ER>
+where
+    L: IntoFuture<Output = Result<OL, EL>>,
+    R: IntoFuture<Output = Result<OR, ER>>,
+{
+    futs::RaceOk::new(left.into_future(), right.into_future())
+}
+
+// ======================================= race_ok_fair() ======================================= \\
+
+#[cfg(feature = "fair-lite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+/// Returns a future polling two futures and returning the output of the first one to succeed, or
+/// the errors returned by both if neither succeeds.
+///
+/// Contrary to [`try_either_fair()`], the returned future will keep polling the other future if
+/// one of them fails. It will choose which future to poll first randomly, each time it is being
+/// polled; for an "unfair" alternative, see [`race_ok()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{race_ok_fair, AggregateError, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = race_ok_fair(
+///     async { Result::<bool, i32>::Err(42) },
+///     async { Result::<i32, bool>::Ok(42) },
+/// ).await;
+/// assert_eq!(out, Ok(Either::Right(42)));
+///
+/// let out = race_ok_fair(
+///     async { Result::<bool, i32>::Err(42) },
+///     async { Result::<i32, bool>::Err(false) },
+/// ).await;
+/// assert_eq!(out, Err(AggregateError { left: 42, right: false }));
+/// #
+/// # });
+/// ```
+pub fn race_ok_fair<OL, OR, EL, ER, L, R>(
+    left: L,
+    right: R,
+) -> futs::RaceOkFair<L::IntoFuture, R::IntoFuture, EL, ER>
+where
+    L: IntoFuture<Output = Result<OL, EL>>,
+    R: IntoFuture<Output = Result<OR, ER>>,
+{
+    futs::RaceOkFair::new(left.into_future(), right.into_future())
+}
+
+// ===================================== try_either_sided() ===================================== \\
+
+/// Returns a future polling two futures and returning a result with the output or error returned
+/// by the first one to complete, with the error indicating which future returned it.
+///
+/// The returned future will always poll `left` first; for a "fair" alternative, see
+/// [`try_either_sided_fair()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{try_either_sided, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = try_either_sided(
+///     async { Ok(42) },
+///     async { Result::<bool, bool>::Err(false) },
+/// ).await;
+/// assert_eq!(out, Ok(Either::Left(42)));
+///
+/// let out = try_either_sided(
+///     future::pending::<Result<bool, i32>>(),
+///     async { Result::<i32, i32>::Err(42) },
+/// ).await;
+/// assert_eq!(out, Err(Either::Right(42)));
+/// #
+/// # });
+/// ```
+pub fn try_either_sided<OL, OR, E, L, R>(
+    left: L,
+    right: R,
+) -> futs::TryEitherSided<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = Result<OL, E>>,
+    R: IntoFuture<Output = Result<OR, E>>,
+{
+    futs::TryEitherSided::new(left.into_future(), right.into_future())
+}
+
+// =================================== try_either_sided_fair() ================================== \\
+
+#[cfg(feature = "fair-lite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+/// Returns a future polling two futures and returning a result with the output or error returned
+/// by the first one to complete, with the error indicating which future returned it.
+///
+/// The returned future will choose which future to poll first randomly, each time it is being
+/// polled; for an "unfair" alternative, see [`try_either_sided()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{try_either_sided_fair, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = try_either_sided_fair(
+///     async { Ok(42) },
+///     async { Result::<bool, bool>::Err(false) },
+/// ).await;
+/// assert!(out == Ok(Either::Left(42)) || out == Err(Either::Right(false)));
+///
+/// let out = try_either_sided_fair(
+///     future::pending::<Result<bool, i32>>(),
+///     async { Result::<i32, i32>::Err(42) },
+/// ).await;
+/// assert_eq!(out, Err(Either::Right(42)));
+/// #
+/// # });
+/// ```
+pub fn try_either_sided_fair<OL, OR, E, L, R>(
+    left: L,
+    right: R,
+) -> futs::TryEitherSidedFair<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = Result<OL, E>>,
+    R: IntoFuture<Output = Result<OR, E>>,
+{
+    futs::TryEitherSidedFair::new(left.into_future(), right.into_future())
+}
+
+// ==================================== try_either_err_into() =================================== \\
+
+/// Returns a future polling two futures and returning a result with the output returned by the
+/// first one to complete, or the error it returned converted into `E`.
+///
+/// Contrary to [`try_either()`], the two futures can return different types of errors, as long as
+/// both can be converted into `E`. The returned future will always poll `left` first; for a "fair"
+/// alternative, see [`try_either_err_into_fair()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{try_either_err_into, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out: Result<_, i64> = try_either_err_into(
+///     async { Result::<i32, i8>::Ok(42) },
+///     async { Result::<bool, i16>::Err(24) },
+/// ).await;
+/// assert_eq!(out, Ok(Either::Left(42)));
+///
+/// let out: Result<_, i64> = try_either_err_into(
+///     future::pending::<Result<bool, i8>>(),
+///     async { Result::<i32, i16>::Err(42) },
+/// ).await;
+/// assert_eq!(out, Err(42));
+/// #
+/// # });
+/// ```
+pub fn try_either_err_into<OL, OR, EL, ER, E, L, R>(
+    left: L,
+    right: R,
+) -> futs::TryEitherErrInto<L::IntoFuture, R::IntoFuture, E>
+where
+    L: IntoFuture<Output = Result<OL, EL>>,
+    R: IntoFuture<Output = Result<OR, ER>>,
+    EL: Into<E>,
+    ER: Into<E>,
+{
+    futs::TryEitherErrInto::new(left.into_future(), right.into_future())
+}
+
+// ================================= try_either_err_into_fair() ================================= \\
+
+#[cfg(feature = "fair-lite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+/// Returns a future polling two futures and returning a result with the output returned by the
+/// first one to complete, or the error it returned converted into `E`.
+///
+/// Contrary to [`try_either_fair()`], the two futures can return different types of errors, as
+/// long as both can be converted into `E`. The returned future will choose which future to poll
+/// first randomly, each time it is being polled; for an "unfair" alternative, see
+/// [`try_either_err_into()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{try_either_err_into_fair, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out: Result<_, i64> = try_either_err_into_fair(
+///     async { Result::<i32, i8>::Ok(42) },
+///     async { Result::<bool, i16>::Err(24) },
+/// ).await;
+/// assert!(out == Ok(Either::Left(42)) || out == Err(24));
+///
+/// let out: Result<_, i64> = try_either_err_into_fair(
+///     future::pending::<Result<bool, i8>>(),
+///     async { Result::<i32, i16>::Err(42) },
+/// ).await;
+/// assert_eq!(out, Err(42));
+/// #
+/// # });
+/// ```
+pub fn try_either_err_into_fair<OL, OR, EL, ER, E, L, R>(
+    left: L,
+    right: R,
+) -> futs::TryEitherErrIntoFair<L::IntoFuture, R::IntoFuture, E>
+where
+    L: IntoFuture<Output = Result<OL, EL>>,
+    R: IntoFuture<Output = Result<OR, ER>>,
+    EL: Into<E>,
+    ER: Into<E>,
+{
+    futs::TryEitherErrIntoFair::new(left.into_future(), right.into_future())
+}
+
+// ====================================== try_either_errs() ===================================== \\
+
+/// Returns a future polling two futures and returning a result with the output or error returned
+/// by the first one to complete.
+///
+/// Contrary to [`try_either()`], the two futures can return different types of errors, the error
+/// returned being an [`Either`] of both. The returned future will always poll `left` first; for a
+/// "fair" alternative, see [`try_either_errs_fair()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{try_either_errs, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = try_either_errs(
+///     async { Result::<i32, i32>::Ok(42) },
+///     async { Result::<bool, bool>::Err(false) },
+/// ).await;
+/// assert_eq!(out, Ok(Either::Left(42)));
+///
+/// let out = try_either_errs(
+///     future::pending::<Result<bool, bool>>(),
+///     async { Result::<i32, i32>::Err(42) },
+/// ).await;
+/// assert_eq!(out, Err(Either::Right(42)));
+/// #
+/// # });
+/// ```
+pub fn try_either_errs<OL, OR, EL, ER, L, R>(
+    left: L,
+    right: R,
+) -> futs::TryEitherErrs<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = Result<OL, EL>>,
+    R: IntoFuture<Output = Result<OR, ER>>,
+{
+    futs::TryEitherErrs::new(left.into_future(), right.into_future())
+}
+
+// =================================== try_either_errs_fair() =================================== \\
+
+#[cfg(feature = "fair-lite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+/// Returns a future polling two futures and returning a result with the output or error returned
+/// by the first one to complete.
+///
+/// Contrary to [`try_either_fair()`], the two futures can return different types of errors, the
+/// error returned being an [`Either`] of both. The returned future will choose which future to
+/// poll first randomly, each time it is being polled; for an "unfair" alternative, see
+/// [`try_either_errs()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{try_either_errs_fair, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = try_either_errs_fair(
+///     async { Result::<i32, i32>::Ok(42) },
+///     async { Result::<bool, bool>::Err(false) },
+/// ).await;
+/// assert!(out == Ok(Either::Left(42)) || out == Err(Either::Right(false)));
+///
+/// let out = try_either_errs_fair(
+///     future::pending::<Result<bool, bool>>(),
+///     async { Result::<i32, i32>::Err(42) },
+/// ).await;
+/// assert_eq!(out, Err(Either::Right(42)));
+/// #
+/// # });
+/// ```
+pub fn try_either_errs_fair<OL, OR, EL, ER, L, R>(
+    left: L,
+    right: R,
+) -> futs::TryEitherErrsFair<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = Result<OL, EL>>,
+    R: IntoFuture<Output = Result<OR, ER>>,
+{
+    futs::TryEitherErrsFair::new(left.into_future(), right.into_future())
+}
+
+// ==================================== either_control_flow() =================================== \\
+
+/// Returns a future polling two futures and returning the value returned by the first one to
+/// complete, breaking if it did.
+///
+/// The returned future will always poll `left` first; for a "fair" alternative, see
+/// [`either_control_flow_fair()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use core::ops::ControlFlow;
+/// use futures_lite::future;
+/// use futures_either::{either_control_flow, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = either_control_flow(
+///     async { ControlFlow::<bool, i32>::Continue(42) },
+///     async { ControlFlow::<bool, bool>::Break(false) },
+/// ).await;
+/// assert_eq!(out, ControlFlow::Continue(Either::Left(42)));
+///
+/// let out = either_control_flow(
+///     future::pending::<ControlFlow<i32, bool>>(),
+///     async { ControlFlow::<i32, i32>::Break(42) },
+/// ).await;
+/// assert_eq!(out, ControlFlow::Break(42));
+/// #
+/// # });
+/// ```
+pub fn either_control_flow<B, CL, CR, L, R>(
+    left: L,
+    right: R,
+) -> futs::EitherControlFlow<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = ControlFlow<B, CL>>,
+    R: IntoFuture<Output = ControlFlow<B, CR>>,
+{
+    futs::EitherControlFlow::new(left.into_future(), right.into_future())
+}
+
+// ================================= either_control_flow_fair() ================================= \\
+
+#[cfg(feature = "fair-lite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+/// Returns a future polling two futures and returning the value returned by the first one to
+/// complete, breaking if it did.
+///
+/// The returned future will choose which future to poll first randomly, each time it is being
+/// polled; for an "unfair" alternative, see [`either_control_flow()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use core::ops::ControlFlow;
+/// use futures_lite::future;
+/// use futures_either::{either_control_flow_fair, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = either_control_flow_fair(
+///     async { ControlFlow::<bool, i32>::Continue(42) },
+///     async { ControlFlow::<bool, bool>::Break(false) },
+/// ).await;
+/// assert!(out == ControlFlow::Continue(Either::Left(42)) || out == ControlFlow::Break(false));
+///
+/// let out = either_control_flow_fair(
+///     future::pending::<ControlFlow<i32, bool>>(),
+///     async { ControlFlow::<i32, i32>::Break(42) },
+/// ).await;
+/// assert_eq!(out, ControlFlow::Break(42));
+/// #
+/// # });
+/// ```
+pub fn either_control_flow_fair<B, CL, CR, L, R>(
+    left: L,
+    right: R,
+) -> futs::EitherControlFlowFair<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = ControlFlow<B, CL>>,
+    R: IntoFuture<Output = ControlFlow<B, CR>>,
+{
+    futs::EitherControlFlowFair::new(left.into_future(), right.into_future())
+}
+
+// ======================================== either_some() ======================================= \\
+
+/// Returns a future polling two futures and returning the value returned by the first one to
+/// return some, or none if both return none.
+///
+/// The returned future will keep polling the other future if one of them returns none. It will
+/// always poll `left` first; for a "fair" alternative, see [`either_some_fair()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{either_some, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = either_some(
+///     async { Option::<bool>::None },
+///     async { Some(42) },
+/// ).await;
+/// assert_eq!(out, Some(Either::Right(42)));
+///
+/// let out = either_some(
+///     async { Option::<bool>::None },
+///     async { Option::<i32>::None },
+/// ).await;
+/// assert_eq!(out, None);
+/// #
+/// # });
+/// ```
+pub fn either_some<OL, OR, L, R>(
+    left: L,
+    right: R,
+) -> futs::EitherSome<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = Option<OL>>,
+    R: IntoFuture<Output = Option<OR>>,
+{
+    futs::EitherSome::new(left.into_future(), right.into_future())
+}
+
+// ===================================== either_some_fair() ===================================== \\
+
+#[cfg(feature = "fair-lite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+/// Returns a future polling two futures and returning the value returned by the first one to
+/// return some, or none if both return none.
+///
+/// The returned future will keep polling the other future if one of them returns none. It will
+/// choose which future to poll first randomly, each time it is being polled; for an "unfair"
+/// alternative, see [`either_some()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{either_some_fair, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = either_some_fair(
+///     async { Option::<bool>::None },
+///     async { Some(42) },
+/// ).await;
+/// assert_eq!(out, Some(Either::Right(42)));
+///
+/// let out = either_some_fair(
+///     async { Option::<bool>::None },
+///     async { Option::<i32>::None },
+/// ).await;
+/// assert_eq!(out, None);
+/// #
+/// # });
+/// ```
+pub fn either_some_fair<OL, OR, L, R>(
+    left: L,
+    right: R,
+) -> futs::EitherSomeFair<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = Option<OL>>,
+    R: IntoFuture<Output = Option<OR>>,
+{
+    futs::EitherSomeFair::new(left.into_future(), right.into_future())
+}
+
+// ====================================== try_either_opt() ====================================== \\
+
+/// Returns a future polling two futures and returning an option with the value returned by the
+/// first one to complete, or none if it returned none.
+///
+/// The returned future will always poll `left` first; for a "fair" alternative, see
+/// [`try_either_opt_fair()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{try_either_opt, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = try_either_opt(
+///     async { Some(42) },
+///     async { Option::<bool>::None },
+/// ).await;
+/// assert_eq!(out, Some(Either::Left(42)));
+///
+/// let out = try_either_opt(
+///     future::pending::<Option<bool>>(),
+///     async { Option::<i32>::None },
+/// ).await;
+/// assert_eq!(out, None);
+/// #
+/// # });
+/// ```
+pub fn try_either_opt<OL, OR, L, R>(
+    left: L,
+    right: R,
+) -> futs::TryEitherOpt<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = Option<OL>>,
+    R: IntoFuture<Output = Option<OR>>,
+{
+    futs::TryEitherOpt::new(left.into_future(), right.into_future())
+}
+
+// ==================================== try_either_opt_fair() =================================== \\
+
+#[cfg(feature = "fair-lite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+/// Returns a future polling two futures and returning an option with the value returned by the
+/// first one to complete, or none if it returned none.
+///
+/// The returned future will choose which future to poll first randomly, each time it is being
+/// polled; for an "unfair" alternative, see [`try_either_opt()`].
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{try_either_opt_fair, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = try_either_opt_fair(
+///     async { Some(42) },
+///     async { Option::<bool>::None },
+/// ).await;
+/// assert!(out == Some(Either::Left(42)) || out == None);
+///
+/// let out = try_either_opt_fair(
+///     future::pending::<Option<bool>>(),
+///     async { Option::<i32>::None },
+/// ).await;
+/// assert_eq!(out, None);
+/// #
+/// # });
+/// ```
+pub fn try_either_opt_fair<OL, OR, L, R>(
+    left: L,
+    right: R,
+) -> futs::TryEitherOptFair<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = Option<OL>>,
+    R: IntoFuture<Output = Option<OR>>,
+{
+    futs::TryEitherOptFair::new(left.into_future(), right.into_future())
+}
+
+// =========================================== both() =========================================== \\
+
+/// Returns a future polling two futures until both complete and returning their outputs.
+///
+/// The returned future will always poll `left` first.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::both;
+///
+/// # future::block_on(async {
+/// #
+/// let out = both(
+///     async { 42 },
+///     async { false },
+/// ).await;
+/// assert_eq!(out, (42, false));
+///
+/// let out = both(
+///     future::ready(Result::<i32, bool>::Err(false)),
+///     async { 42 },
+/// ).await;
+/// assert_eq!(out, (Err(false), 42));
+/// #
+/// # });
+/// ```
+pub fn both<L, R>(
+    left: L,
+    right: R,
+) -> futs::Both<L::IntoFuture, R::IntoFuture, L::Output, R::Output>
+where
+    L: IntoFuture,
+    R: IntoFuture,
+{
+    futs::Both::new(left.into_future(), right.into_future())
+}
+
+// ========================================= try_both() ========================================= \\
+
+/// Returns a future polling two futures until both complete and returning a result with their
+/// outputs, or with the error returned by the first one to fail.
+///
+/// The returned future will always poll `left` first.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::try_both;
+///
+/// # future::block_on(async {
+/// #
+/// let out = try_both(
+///     async { Result::<i32, i32>::Ok(42) },
+///     async { Ok(false) },
+/// ).await;
+/// assert_eq!(out, Ok((42, false)));
+///
+/// let out = try_both(
+///     future::pending::<Result<bool, i32>>(),
+///     async { Result::<i32, i32>::Err(42) },
+/// ).await;
+/// assert_eq!(out, Err(42));
+/// #
+/// # });
+/// ```
+pub fn try_both<OL, OR, E, L, R>(
+    left: L,
+    right: R,
+) -> futs::TryBoth<L::IntoFuture, R::IntoFuture, OL, OR>
+where
+    L: IntoFuture<Output = Result<OL, E>>,
+    R: IntoFuture<Output = Result<OR, E>>,
+{
+    futs::TryBoth::new(left.into_future(), right.into_future())
+}
+
+// ====================================== either_or_both() ====================================== \\
+
+/// Returns a future polling two futures and returning the output of the first one to complete, or
+/// of both if they complete while being polled at the same time.
+///
+/// Contrary to [`either()`], the returned future will always poll both futures, so that no output
+/// is lost if both of them are ready. It will always poll `left` first.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{either_or_both, EitherOrBoth};
+///
+/// # future::block_on(async {
+/// #
+/// let out = either_or_both(
+///     async { 42 },
+///     async { false },
+/// ).await;
+/// assert_eq!(out, EitherOrBoth::Both(42, false));
+///
+/// let out = either_or_both(
+///     future::pending::<bool>(),
+///     async { 42 },
+/// ).await;
+/// assert_eq!(out, EitherOrBoth::Right(42));
+/// #
+/// # });
+/// ```
+pub const fn either_or_both<L, R>(left: L, right: R) -> futs::EitherOrBoth<L, R> {
+    futs::EitherOrBoth { left, right }
+}
+
+// ==================================== try_either_recover() ==================================== \\
+
+/// Returns a future polling two futures and returning a result with the output returned by the
+/// first one to complete, or the error returned by the first one to fail without being recovered.
+///
+/// When one of the futures fails, its error is passed to the matching `recover_left` or
+/// `recover_right` closure, which can either return a new future to replace it with, or the error
+/// to return. Replacement futures are polled right away, and the other future keeps its progress.
+///
+/// The returned future will always poll `left` first.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{try_either_recover, Either};
+///
+/// async fn get(out: Option<Result<i32, i32>>) -> Result<i32, i32> {
+///     match out {
+///         Some(out) => out,
+///         None => future::pending().await,
+///     }
+/// }
+///
+/// # future::block_on(async {
+/// #
+/// let mut retries = 0;
+/// let out = try_either_recover(
+///     get(Some(Err(24))),
+///     get(None),
+///     |err| {
+///         retries += 1;
+///         if retries < 3 { Ok(get(Some(Err(err)))) } else { Ok(get(Some(Ok(42)))) }
+///     },
+///     Err,
+/// ).await;
+/// assert_eq!(out, Ok(Either::Left(42)));
+///
+/// let out = try_either_recover(get(None), get(Some(Err(42))), Err, Err).await;
+/// assert_eq!(out, Err(42));
+/// #
+/// # });
+/// ```
+pub fn try_either_recover<OL, OR, E, L, R, FL, FR>(
+    left: L,
+    right: R,
+    recover_left: FL,
+    recover_right: FR,
+) -> futs::TryEitherRecover<L::IntoFuture, R::IntoFuture, FL, FR>
+where
+    L: IntoFuture<Output = Result<OL, E>>,
+    R: IntoFuture<Output = Result<OR, E>>,
+    FL: FnMut(E) -> Result<L::IntoFuture, E>,
+    FR: FnMut(E) -> Result<R::IntoFuture, E>,
+{
+    futs::TryEitherRecover::new(
+        left.into_future(),
+        right.into_future(),
+        recover_left,
+        recover_right,
+    )
+}
+
+// ==================================== try_either_generic() ==================================== \\
+
+#[cfg(feature = "nightly")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nightly")))]
+/// Returns a future polling two futures and returning the output of the first one to complete,
+/// short-circuiting if it did.
+///
+/// This generalizes [`try_either()`], [`try_either_opt()`] and [`either_control_flow()`] to any
+/// output implementing [`Try`], as long as both outputs have the same [`Residual`]. The returned
+/// future will always poll `left` first.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// # #![feature(try_trait_v2)]
+/// use futures_lite::future;
+/// use futures_either::{try_either_generic, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = try_either_generic(
+///     async { Ok(42) },
+///     async { Result::<bool, bool>::Err(false) },
+/// ).await;
+/// assert_eq!(out, Ok(Either::Left(42)));
+///
+/// let out = try_either_generic(
+///     future::pending::<Option<bool>>(),
+///     async { Option::<i32>::None },
+/// ).await;
+/// assert_eq!(out, None);
+/// #
+/// # });
+/// ```
+///
+/// [`Try`]: core::ops::Try
+/// [`Residual`]: core::ops::Try::Residual
+pub fn try_either_generic<L, R>(
+    left: L,
+    right: R,
+) -> futs::TryEitherGeneric<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture,
+    R: IntoFuture,
+    L::Output: Try,
+    R::Output: Try<Residual = <L::Output as Try>::Residual>,
+    <L::Output as Try>::Residual:
+        Residual<Either<<L::Output as Try>::Output, <R::Output as Try>::Output>>,
+{
+    futs::TryEitherGeneric::new(left.into_future(), right.into_future())
+}
+
+// =================================== try_either_prefer_ok() =================================== \\
+
+/// Returns a future polling two futures and returning a result with the output or error returned
+/// by the first one to complete, preferring outputs over errors if both complete while being
+/// polled at the same time.
+///
+/// Contrary to [`try_either()`], the returned future will always poll both futures, and will only
+/// fail if none of the futures that completed succeeded. It will always poll `left` first.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{try_either_prefer_ok, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = try_either_prefer_ok(
+///     async { Result::<bool, bool>::Err(false) },
+///     async { Ok(42) },
+/// ).await;
+/// assert_eq!(out, Ok(Either::Right(42)));
+///
+/// let out = try_either_prefer_ok(
+///     future::pending::<Result<bool, i32>>(),
+///     async { Result::<i32, i32>::Err(42) },
+/// ).await;
+/// assert_eq!(out, Err(42));
+/// #
+/// # });
+/// ```
+pub fn try_either_prefer_ok<OL, OR, E, L, R>(
+    left: L,
+    right: R,
+) -> futs::TryEitherPreferOk<L::IntoFuture, R::IntoFuture>
+where
+    L: IntoFuture<Output = Result<OL, E>>,
+    R: IntoFuture<Output = Result<OR, E>>,
+{
+    futs::TryEitherPreferOk::new(left.into_future(), right.into_future())
+}
+
+// ======================================== either_map() ======================================== \\
+
+/// Returns a future polling two futures and returning the output of the first one to complete,
+/// mapped by either `map_left` or `map_right` to a common type.
+///
+/// The returned future will always poll `left` first.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::either_map;
+///
+/// # future::block_on(async {
+/// #
+/// let out = either_map(
+///     async { 42 },
+///     async { "24" },
+///     |left| left,
+///     |right| right.parse().unwrap(),
+/// ).await;
+/// assert_eq!(out, 42);
+///
+/// let out = either_map(
+///     future::pending::<i32>(),
+///     async { "24" },
+///     |left| left,
+///     |right| right.parse().unwrap(),
+/// ).await;
+/// assert_eq!(out, 24);
+/// #
+/// # });
+/// ```
+pub fn either_map<T, L, R, FL, FR>(
+    left: L,
+    right: R,
+    map_left: FL,
+    map_right: FR,
+) -> futs::EitherMap<L::IntoFuture, R::IntoFuture, FL, FR>
+where
+    L: IntoFuture,
+    R: IntoFuture,
+    FL: FnOnce(L::Output) -> T,
+    FR: FnOnce(R::Output) -> T,
+{
+    futs::EitherMap {
+        fut: either(left, right),
+        map_left: Some(map_left),
+        map_right: Some(map_right),
+    }
+}
+
+// ======================================== either_into() ======================================= \\
+
+/// Returns a future polling two futures and returning the output of the first one to complete,
+/// converted into `T`.
+///
+/// This is the same as calling [`unify()`] on the future returned by [`either()`]. The returned
+/// future will always poll `left` first.
+///
+/// `left` and `right` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::either_into;
+///
+/// # future::block_on(async {
+/// #
+/// let out = either_into::<i64, _, _>(
+///     async { 42i32 },
+///     async { 24u8 },
+/// ).await;
+/// assert_eq!(out, 42);
+///
+/// let out = either_into::<i64, _, _>(
+///     future::pending::<i32>(),
+///     async { 24u8 },
+/// ).await;
+/// assert_eq!(out, 24);
+/// #
+/// # });
+/// ```
+///
+/// [`unify()`]: futs::Either::unify()
+pub fn either_into<T, L, R>(
+    left: L,
+    right: R,
+) -> futs::Unify<futs::Either<L::IntoFuture, R::IntoFuture>, T>
+where
+    L: IntoFuture,
+    R: IntoFuture,
+    L::Output: Into<T>,
+    R::Output: Into<T>,
+{
+    either(left, right).unify()
+}
+
+// ========================================= fallback() ========================================= \\
+
+/// Returns a future polling a future and, only if it fails, a fallback future created from its
+/// error by `make_right`, returning a result with the output of the one which succeeded or the
+/// error returned by the fallback.
+///
+/// Contrary to [`try_either()`] and [`race_ok()`], the fallback future isn't created, and thus
+/// doesn't start, until `left` has failed.
+///
+/// `left` can be anything implementing [`IntoFuture`], and gets converted into a future right
+/// away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_either::{fallback, Either};
+///
+/// # futures_lite::future::block_on(async {
+/// #
+/// let out = fallback(
+///     async { Result::<i32, bool>::Ok(42) },
+///     |_| async { Result::<bool, bool>::Err(false) },
+/// ).await;
+/// assert_eq!(out, Ok(Either::Left(42)));
+///
+/// let out = fallback(
+///     async { Result::<bool, i32>::Err(24) },
+///     |err| async move { Result::<i32, i32>::Ok(err + 18) },
+/// ).await;
+/// assert_eq!(out, Ok(Either::Right(42)));
+/// #
+/// # });
+/// ```
+pub fn fallback<OL, OR, EL, ER, L, R, F>(
+    left: L,
+    make_right: F,
+) -> futs::Fallback<L::IntoFuture, R, F>
+where
+    L: IntoFuture<Output = Result<OL, EL>>,
+    R: Future<Output = Result<OR, ER>>,
+    F: FnOnce(EL) -> R,
+{
+    futs::Fallback::new(left.into_future(), make_right)
+}
+
+// ====================================== fallback_after() ====================================== \\
+
+/// Returns a future polling a future and, once `delay` completes, a fallback future created by
+/// `make_right`, returning the output of the first one to complete.
+///
+/// Until `delay` completes, the returned future only polls `left`; then, it races `left` and the
+/// fallback future, always polling `left` first. `delay` can be any future, like a timer provided
+/// by an async runtime.
+///
+/// `left` and `delay` can be anything implementing [`IntoFuture`], and get converted into futures
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{fallback_after, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = fallback_after(
+///     async { 42 },
+///     future::pending::<()>(),
+///     || async { false },
+/// ).await;
+/// assert_eq!(out, Either::Left(42));
+///
+/// let out = fallback_after(
+///     future::pending::<bool>(),
+///     async {},
+///     || async { 42 },
+/// ).await;
+/// assert_eq!(out, Either::Right(42));
+/// #
+/// # });
+/// ```
+pub fn fallback_after<L, D, R, F>(
+    left: L,
+    delay: D,
+    make_right: F,
+) -> futs::FallbackAfter<L::IntoFuture, D::IntoFuture, R, F>
+where
+    L: IntoFuture,
+    D: IntoFuture<Output = ()>,
+    R: Future,
+    F: FnOnce() -> R,
+{
+    futs::FallbackAfter::new(left.into_future(), delay.into_future(), make_right)
+}
+
+// =========================================== hedge() ========================================== \\
+
+/// Returns a future polling a future created by `factory` and, once `delay` completes, a second one
+/// created by it, returning the output of the first one to complete.
+///
+/// This is the same as calling [`fallback_after()`] with `factory`'s first future and `factory`
+/// itself, returning the output directly. The second future is thus only created if the first one
+/// is slow, the other one being dropped once one of them completes.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::hedge;
+///
+/// # future::block_on(async {
+/// #
+/// let mut attempts = 0;
+/// let out = hedge(
+///     || {
+///         attempts += 1;
+///         let attempt = attempts;
+///         async move {
+///             if attempt == 1 {
+///                 future::pending::<i32>().await
+///             } else {
+///                 42
+///             }
+///         }
+///     },
+///     async {},
+/// ).await;
+/// assert_eq!(out, 42);
+/// assert_eq!(attempts, 2);
+/// #
+/// # });
+/// ```
+pub fn hedge<T, F, D, Fut>(mut factory: F, delay: D) -> futs::Hedge<F, D, Fut>
+where
+    F: FnMut() -> Fut,
+    D: Future<Output = ()>,
+    Fut: Future<Output = T>,
+{
+    futs::Hedge { fut: fallback_after(factory(), delay, factory), }
+}
+
+// ====================================== left() & right() ====================================== \\
+
+/// Returns a future polling `fut`, whose type can be unified with the one returned by [`right()`].
+///
+/// This allows to return different futures from different branches, as long as they have the same
+/// output. `fut` can be anything implementing [`IntoFuture`], and gets converted into a future
+/// right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use core::future::Future;
+/// use futures_either::{left, right};
+///
+/// fn get(cached: Option<i32>) -> impl Future<Output = i32> {
+///     if let Some(out) = cached {
+///         left(async move { out })
+///     } else {
+///         right(async { 42 })
+///     }
+/// }
+///
+/// # futures_lite::future::block_on(async {
+/// #
+/// assert_eq!(get(Some(24)).await, 24);
+/// assert_eq!(get(None).await, 42);
+/// #
+/// # });
+/// ```
+pub fn left<L, R>(fut: L) -> futs::Branch<L::IntoFuture, R>
+where
+    L: IntoFuture,
+    R: Future<Output = L::Output>,
+{
+    futs::Branch::left(fut.into_future())
+}
+
+/// Returns a future polling `fut`, whose type can be unified with the one returned by [`left()`].
+///
+/// See [`left()`] for an example.
+pub fn right<L, R>(fut: R) -> futs::Branch<L, R::IntoFuture>
+where
+    L: Future,
+    R: IntoFuture<Output = L::Output>,
+{
+    futs::Branch::right(fut.into_future())
+}
+
+// ======================================== either_lazy() ======================================= \\
+
+/// Returns a future creating two futures with `make_left` and `make_right` when first polled, and
+/// then polling them and returning the output of the first one to complete, as [`either()`].
+///
+/// Contrary to [`either()`], no work is done to create the futures if the returned future gets
+/// dropped before being polled.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{either_lazy, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = either_lazy(
+///     || future::pending::<bool>(),
+///     || async { 42 },
+/// ).await;
+/// assert_eq!(out, Either::Right(42));
+/// #
+/// # });
+/// ```
+pub const fn either_lazy<L, R, ML, MR>(
+    make_left: ML,
+    make_right: MR,
+) -> futs::EitherLazy<L, R, ML, MR>
+where
+    L: Future,
+    R: Future,
+    ML: FnOnce() -> L,
+    MR: FnOnce() -> R,
+{
+    futs::EitherLazy { fut: None, make_left: Some(make_left), make_right: Some(make_right) }
+}
+
+// ========================================= alternate() ======================================== \\
+
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+/// Returns a [`Stream`] awaiting a future created by `make_left`, yielding its output, then
+/// awaiting a future created by `make_right`, yielding its output, and starting over.
+///
+/// Each future is only created once the previous one has completed.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::{future, StreamExt};
+/// use futures_either::{alternate, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let mut requests = 0;
+/// let mut acks = 0;
+/// let out = alternate(
+///     || { requests += 1; future::ready(requests) },
+///     || { acks += 1; future::ready(acks) },
+/// ).take(3).collect::<Vec<_>>().await;
+/// assert_eq!(out, [Either::Left(1), Either::Right(1), Either::Left(2)]);
+/// #
+/// # });
+/// ```
+///
+/// [`Stream`]: futures_core::Stream
+pub const fn alternate<L, R, ML, MR>(make_left: ML, make_right: MR) -> futs::Alternate<L, R, ML, MR>
+where
+    L: Future,
+    R: Future,
+    ML: FnMut() -> L,
+    MR: FnMut() -> R,
+{
+    futs::Alternate { fut: None, next: Side::Left, make_left, make_right }
+}
+
+// ======================================== dyn_either() ======================================== \\
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+/// Returns a future polling two boxed, type-erased futures returning the same type, and returning
+/// the output of the first one to complete, as [`either()`].
+///
+/// Contrary to [`either()`], the type of the returned future only depends on the type of the
+/// output of the futures, allowing to use it across API boundaries (e.g. with plugins).
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{dyn_either, DynEither, Either};
+///
+/// fn race(left: i32, right: i32) -> DynEither<'static, i32> {
+///     dyn_either(Box::pin(future::pending()), Box::pin(async move { left + right }))
+/// }
+///
+/// # future::block_on(async {
+/// #
+/// assert_eq!(race(24, 18).await, Either::Right(42));
+/// #
+/// # });
+/// ```
+pub const fn dyn_either<'a, T>(
+    left: Pin<Box<dyn Future<Output = T> + Send + 'a>>,
+    right: Pin<Box<dyn Future<Output = T> + Send + 'a>>,
+) -> DynEither<'a, T> {
+    futs::Either::new(left, right)
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+/// Returns a future polling two boxed, type-erased futures returning the same type, which aren't
+/// [`Send`], and returning the output of the first one to complete, as [`dyn_either()`].
+///
+/// ## Example
+///
+/// ```rust
+/// use std::rc::Rc;
+///
+/// use futures_lite::future;
+/// use futures_either::{dyn_either_local, Either};
+///
+/// # future::block_on(async {
+/// #
+/// let out = dyn_either_local(
+///     Box::pin(async { Rc::new(42) }),
+///     Box::pin(future::pending()),
+/// ).await;
+/// assert_eq!(out, Either::Left(Rc::new(42)));
+/// #
+/// # });
+/// ```
+pub const fn dyn_either_local<'a, T>(
+    left: Pin<Box<dyn Future<Output = T> + 'a>>,
+    right: Pin<Box<dyn Future<Output = T> + 'a>>,
+) -> LocalDynEither<'a, T> {
+    futs::Either::new(left, right)
+}
+
+// ================================== either3() & try_either3() ================================= \\
+
+either_n! {
+    /// Returns a future polling three futures and returning the output of the first one to
+    /// complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either3, Either3};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either3(
+    ///     async { 42 },
+    ///     async { false },
+    ///     async { false },
+    /// ).await;
+    /// assert_eq!(out, Either3::First(42));
+    ///
+    /// let out = either3(
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     async { 42 },
+    /// ).await;
+    /// assert_eq!(out, Either3::Third(42));
+    /// #
+    /// # });
+    /// ```
+    fn either3 -> futs::Either3, Either3 {
+        First(A, OA, first) { is_first, map_first, into_first },
+        Second(B, OB, second) { is_second, map_second, into_second },
+        Third(C, OC, third) { is_third, map_third, into_third },
+    }
+
+    /// Returns a future polling three futures and returning a result with the output or error
+    /// returned by the first one to complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{try_either3, Either3};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = try_either3(
+    ///     async { Ok(42) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    /// ).await;
+    /// assert_eq!(out, Ok(Either3::First(42)));
+    ///
+    /// let out = try_either3(
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     async { Result::<i32, i32>::Err(42) },
+    /// ).await;
+    /// assert_eq!(out, Err(42));
+    /// #
+    /// # });
+    /// ```
+    fn try_either3 -> futs::TryEither3;
+}
+
+// ================================== either4() & try_either4() ================================= \\
+
+either_n! {
+    /// Returns a future polling four futures and returning the output of the first one to
+    /// complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either4, Either4};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either4(
+    ///     async { 42 },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    /// ).await;
+    /// assert_eq!(out, Either4::First(42));
+    ///
+    /// let out = either4(
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     async { 42 },
+    /// ).await;
+    /// assert_eq!(out, Either4::Fourth(42));
+    /// #
+    /// # });
+    /// ```
+    fn either4 -> futs::Either4, Either4 {
+        First(A, OA, first) { is_first, map_first, into_first },
+        Second(B, OB, second) { is_second, map_second, into_second },
+        Third(C, OC, third) { is_third, map_third, into_third },
+        Fourth(D, OD, fourth) { is_fourth, map_fourth, into_fourth },
+    }
+
+    /// Returns a future polling four futures and returning a result with the output or error
+    /// returned by the first one to complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{try_either4, Either4};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = try_either4(
+    ///     async { Ok(42) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    /// ).await;
+    /// assert_eq!(out, Ok(Either4::First(42)));
+    ///
+    /// let out = try_either4(
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     async { Result::<i32, i32>::Err(42) },
+    /// ).await;
+    /// assert_eq!(out, Err(42));
+    /// #
+    /// # });
+    /// ```
+    fn try_either4 -> futs::TryEither4;
+}
+
+// ================================== either5() & try_either5() ================================= \\
+
+either_n! {
+    /// Returns a future polling five futures and returning the output of the first one to
+    /// complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either5, Either5};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either5(
+    ///     async { 42 },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    /// ).await;
+    /// assert_eq!(out, Either5::First(42));
+    ///
+    /// let out = either5(
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     async { 42 },
+    /// ).await;
+    /// assert_eq!(out, Either5::Fifth(42));
+    /// #
+    /// # });
+    /// ```
+    fn either5 -> futs::Either5, Either5 {
+        First(A, OA, first) { is_first, map_first, into_first },
+        Second(B, OB, second) { is_second, map_second, into_second },
+        Third(C, OC, third) { is_third, map_third, into_third },
+        Fourth(D, OD, fourth) { is_fourth, map_fourth, into_fourth },
+        Fifth(E, OE, fifth) { is_fifth, map_fifth, into_fifth },
+    }
+
+    /// Returns a future polling five futures and returning a result with the output or error
+    /// returned by the first one to complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{try_either5, Either5};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = try_either5(
+    ///     async { Ok(42) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    /// ).await;
+    /// assert_eq!(out, Ok(Either5::First(42)));
+    ///
+    /// let out = try_either5(
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     async { Result::<i32, i32>::Err(42) },
+    /// ).await;
+    /// assert_eq!(out, Err(42));
+    /// #
+    /// # });
+    /// ```
+    fn try_either5 -> futs::TryEither5;
+}
+
+// ================================== either6() & try_either6() ================================= \\
+
+either_n! {
+    /// Returns a future polling six futures and returning the output of the first one to
+    /// complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either6, Either6};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either6(
+    ///     async { 42 },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    /// ).await;
+    /// assert_eq!(out, Either6::First(42));
+    ///
+    /// let out = either6(
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     async { 42 },
+    /// ).await;
+    /// assert_eq!(out, Either6::Sixth(42));
+    /// #
+    /// # });
+    /// ```
+    fn either6 -> futs::Either6, Either6 {
+        First(A, OA, first) { is_first, map_first, into_first },
+        Second(B, OB, second) { is_second, map_second, into_second },
+        Third(C, OC, third) { is_third, map_third, into_third },
+        Fourth(D, OD, fourth) { is_fourth, map_fourth, into_fourth },
+        Fifth(E, OE, fifth) { is_fifth, map_fifth, into_fifth },
+        Sixth(F, OF, sixth) { is_sixth, map_sixth, into_sixth },
+    }
+
+    /// Returns a future polling six futures and returning a result with the output or error
+    /// returned by the first one to complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{try_either6, Either6};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = try_either6(
+    ///     async { Ok(42) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    /// ).await;
+    /// assert_eq!(out, Ok(Either6::First(42)));
+    ///
+    /// let out = try_either6(
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     async { Result::<i32, i32>::Err(42) },
+    /// ).await;
+    /// assert_eq!(out, Err(42));
+    /// #
+    /// # });
+    /// ```
+    fn try_either6 -> futs::TryEither6;
+}
+
+// ================================== either7() & try_either7() ================================= \\
+
+either_n! {
+    /// Returns a future polling seven futures and returning the output of the first one to
+    /// complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either7, Either7};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either7(
+    ///     async { 42 },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    /// ).await;
+    /// assert_eq!(out, Either7::First(42));
+    ///
+    /// let out = either7(
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     async { 42 },
+    /// ).await;
+    /// assert_eq!(out, Either7::Seventh(42));
+    /// #
+    /// # });
+    /// ```
+    fn either7 -> futs::Either7, Either7 {
+        First(A, OA, first) { is_first, map_first, into_first },
+        Second(B, OB, second) { is_second, map_second, into_second },
+        Third(C, OC, third) { is_third, map_third, into_third },
+        Fourth(D, OD, fourth) { is_fourth, map_fourth, into_fourth },
+        Fifth(E, OE, fifth) { is_fifth, map_fifth, into_fifth },
+        Sixth(F, OF, sixth) { is_sixth, map_sixth, into_sixth },
+        Seventh(G, OG, seventh) { is_seventh, map_seventh, into_seventh },
+    }
+
+    /// Returns a future polling seven futures and returning a result with the output or error
+    /// returned by the first one to complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{try_either7, Either7};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = try_either7(
+    ///     async { Ok(42) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    /// ).await;
+    /// assert_eq!(out, Ok(Either7::First(42)));
+    ///
+    /// let out = try_either7(
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     async { Result::<i32, i32>::Err(42) },
+    /// ).await;
+    /// assert_eq!(out, Err(42));
+    /// #
+    /// # });
+    /// ```
+    fn try_either7 -> futs::TryEither7;
+}
+
+// ================================== either8() & try_either8() ================================= \\
+
+either_n! {
+    /// Returns a future polling eight futures and returning the output of the first one to
+    /// complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either8, Either8};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either8(
+    ///     async { 42 },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    /// ).await;
+    /// assert_eq!(out, Either8::First(42));
+    ///
+    /// let out = either8(
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     async { 42 },
+    /// ).await;
+    /// assert_eq!(out, Either8::Eighth(42));
+    /// #
+    /// # });
+    /// ```
+    fn either8 -> futs::Either8, Either8 {
+        First(A, OA, first) { is_first, map_first, into_first },
+        Second(B, OB, second) { is_second, map_second, into_second },
+        Third(C, OC, third) { is_third, map_third, into_third },
+        Fourth(D, OD, fourth) { is_fourth, map_fourth, into_fourth },
+        Fifth(E, OE, fifth) { is_fifth, map_fifth, into_fifth },
+        Sixth(F, OF, sixth) { is_sixth, map_sixth, into_sixth },
+        Seventh(G, OG, seventh) { is_seventh, map_seventh, into_seventh },
+        Eighth(H, OH, eighth) { is_eighth, map_eighth, into_eighth },
+    }
+
+    /// Returns a future polling eight futures and returning a result with the output or error
+    /// returned by the first one to complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{try_either8, Either8};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = try_either8(
+    ///     async { Ok(42) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    /// ).await;
+    /// assert_eq!(out, Ok(Either8::First(42)));
+    ///
+    /// let out = try_either8(
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     async { Result::<i32, i32>::Err(42) },
+    /// ).await;
+    /// assert_eq!(out, Err(42));
+    /// #
+    /// # });
+    /// ```
+    fn try_either8 -> futs::TryEither8;
+}
+
+// ================================== either9() & try_either9() ================================= \\
+
+either_n! {
+    /// Returns a future polling nine futures and returning the output of the first one to
+    /// complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either9, Either9};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either9(
+    ///     async { 42 },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    /// ).await;
+    /// assert_eq!(out, Either9::First(42));
+    ///
+    /// let out = either9(
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     async { 42 },
+    /// ).await;
+    /// assert_eq!(out, Either9::Ninth(42));
+    /// #
+    /// # });
+    /// ```
+    fn either9 -> futs::Either9, Either9 {
+        First(A, OA, first) { is_first, map_first, into_first },
+        Second(B, OB, second) { is_second, map_second, into_second },
+        Third(C, OC, third) { is_third, map_third, into_third },
+        Fourth(D, OD, fourth) { is_fourth, map_fourth, into_fourth },
+        Fifth(E, OE, fifth) { is_fifth, map_fifth, into_fifth },
+        Sixth(F, OF, sixth) { is_sixth, map_sixth, into_sixth },
+        Seventh(G, OG, seventh) { is_seventh, map_seventh, into_seventh },
+        Eighth(H, OH, eighth) { is_eighth, map_eighth, into_eighth },
+        Ninth(I, OI, ninth) { is_ninth, map_ninth, into_ninth },
+    }
+
+    /// Returns a future polling nine futures and returning a result with the output or error
+    /// returned by the first one to complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{try_either9, Either9};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = try_either9(
+    ///     async { Ok(42) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    /// ).await;
+    /// assert_eq!(out, Ok(Either9::First(42)));
+    ///
+    /// let out = try_either9(
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     async { Result::<i32, i32>::Err(42) },
+    /// ).await;
+    /// assert_eq!(out, Err(42));
+    /// #
+    /// # });
+    /// ```
+    fn try_either9 -> futs::TryEither9;
+}
+
+// ================================= either10() & try_either10() ================================ \\
+
+either_n! {
+    /// Returns a future polling ten futures and returning the output of the first one to
+    /// complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either10, Either10};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either10(
+    ///     async { 42 },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    /// ).await;
+    /// assert_eq!(out, Either10::First(42));
+    ///
+    /// let out = either10(
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     async { 42 },
+    /// ).await;
+    /// assert_eq!(out, Either10::Tenth(42));
+    /// #
+    /// # });
+    /// ```
+    fn either10 -> futs::Either10, Either10 {
+        First(A, OA, first) { is_first, map_first, into_first },
+        Second(B, OB, second) { is_second, map_second, into_second },
+        Third(C, OC, third) { is_third, map_third, into_third },
+        Fourth(D, OD, fourth) { is_fourth, map_fourth, into_fourth },
+        Fifth(E, OE, fifth) { is_fifth, map_fifth, into_fifth },
+        Sixth(F, OF, sixth) { is_sixth, map_sixth, into_sixth },
+        Seventh(G, OG, seventh) { is_seventh, map_seventh, into_seventh },
+        Eighth(H, OH, eighth) { is_eighth, map_eighth, into_eighth },
+        Ninth(I, OI, ninth) { is_ninth, map_ninth, into_ninth },
+        Tenth(J, OJ, tenth) { is_tenth, map_tenth, into_tenth },
+    }
+
+    /// Returns a future polling ten futures and returning a result with the output or error
+    /// returned by the first one to complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{try_either10, Either10};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = try_either10(
+    ///     async { Ok(42) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    /// ).await;
+    /// assert_eq!(out, Ok(Either10::First(42)));
+    ///
+    /// let out = try_either10(
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     async { Result::<i32, i32>::Err(42) },
+    /// ).await;
+    /// assert_eq!(out, Err(42));
+    /// #
+    /// # });
+    /// ```
+    fn try_either10 -> futs::TryEither10;
+}
+
+// ================================= either11() & try_either11() ================================ \\
+
+either_n! {
+    /// Returns a future polling eleven futures and returning the output of the first one to
+    /// complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either11, Either11};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either11(
+    ///     async { 42 },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    /// ).await;
+    /// assert_eq!(out, Either11::First(42));
+    ///
+    /// let out = either11(
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     async { 42 },
+    /// ).await;
+    /// assert_eq!(out, Either11::Eleventh(42));
+    /// #
+    /// # });
+    /// ```
+    fn either11 -> futs::Either11, Either11 {
+        First(A, OA, first) { is_first, map_first, into_first },
+        Second(B, OB, second) { is_second, map_second, into_second },
+        Third(C, OC, third) { is_third, map_third, into_third },
+        Fourth(D, OD, fourth) { is_fourth, map_fourth, into_fourth },
+        Fifth(E, OE, fifth) { is_fifth, map_fifth, into_fifth },
+        Sixth(F, OF, sixth) { is_sixth, map_sixth, into_sixth },
+        Seventh(G, OG, seventh) { is_seventh, map_seventh, into_seventh },
+        Eighth(H, OH, eighth) { is_eighth, map_eighth, into_eighth },
+        Ninth(I, OI, ninth) { is_ninth, map_ninth, into_ninth },
+        Tenth(J, OJ, tenth) { is_tenth, map_tenth, into_tenth },
+        Eleventh(K, OK, eleventh) { is_eleventh, map_eleventh, into_eleventh },
+    }
+
+    /// Returns a future polling eleven futures and returning a result with the output or error
+    /// returned by the first one to complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{try_either11, Either11};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = try_either11(
+    ///     async { Ok(42) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    /// ).await;
+    /// assert_eq!(out, Ok(Either11::First(42)));
+    ///
+    /// let out = try_either11(
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     async { Result::<i32, i32>::Err(42) },
+    /// ).await;
+    /// assert_eq!(out, Err(42));
+    /// #
+    /// # });
+    /// ```
+    fn try_either11 -> futs::TryEither11;
+}
+
+// ================================= either12() & try_either12() ================================ \\
+
+either_n! {
+    /// Returns a future polling twelve futures and returning the output of the first one to
+    /// complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either12, Either12};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either12(
+    ///     async { 42 },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    ///     async { false },
+    /// ).await;
+    /// assert_eq!(out, Either12::First(42));
+    ///
+    /// let out = either12(
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     future::pending::<bool>(),
+    ///     async { 42 },
+    /// ).await;
+    /// assert_eq!(out, Either12::Twelfth(42));
+    /// #
+    /// # });
+    /// ```
+    fn either12 -> futs::Either12, Either12 {
+        First(A, OA, first) { is_first, map_first, into_first },
+        Second(B, OB, second) { is_second, map_second, into_second },
+        Third(C, OC, third) { is_third, map_third, into_third },
+        Fourth(D, OD, fourth) { is_fourth, map_fourth, into_fourth },
+        Fifth(E, OE, fifth) { is_fifth, map_fifth, into_fifth },
+        Sixth(F, OF, sixth) { is_sixth, map_sixth, into_sixth },
+        Seventh(G, OG, seventh) { is_seventh, map_seventh, into_seventh },
+        Eighth(H, OH, eighth) { is_eighth, map_eighth, into_eighth },
+        Ninth(I, OI, ninth) { is_ninth, map_ninth, into_ninth },
+        Tenth(J, OJ, tenth) { is_tenth, map_tenth, into_tenth },
+        Eleventh(K, OK, eleventh) { is_eleventh, map_eleventh, into_eleventh },
+        Twelfth(L, OL, twelfth) { is_twelfth, map_twelfth, into_twelfth },
+    }
+
+    /// Returns a future polling twelve futures and returning a result with the output or error
+    /// returned by the first one to complete.
+    ///
+    /// The returned future will always poll the futures in the order they were given.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{try_either12, Either12};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = try_either12(
+    ///     async { Ok(42) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    ///     async { Result::<bool, bool>::Err(false) },
+    /// ).await;
+    /// assert_eq!(out, Ok(Either12::First(42)));
+    ///
+    /// let out = try_either12(
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     future::pending::<Result<bool, i32>>(),
+    ///     async { Result::<i32, i32>::Err(42) },
+    /// ).await;
+    /// assert_eq!(out, Err(42));
+    /// #
+    /// # });
+    /// ```
+    fn try_either12 -> futs::TryEither12;
+}
+
+// ========================================== either!() ========================================= \\
+
+/// Returns a future polling two to twelve futures and returning the output of the first one to
+/// complete.
+///
+/// This expands to a call to [`either()`] or to the [`either3()`] to [`either12()`] function
+/// matching the number of futures given, whose output is respectively an [`Either`] or one of
+/// [`Either3`] to [`Either12`].
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{either, Either, Either4};
+///
+/// # future::block_on(async {
+/// #
+/// let out = either!(
+///     async { 42 },
+///     async { false },
+/// ).await;
+/// assert_eq!(out, Either::Left(42));
+///
+/// let out = either!(
+///     future::pending::<bool>(),
+///     future::pending::<bool>(),
+///     async { 42 },
+///     async { false },
+/// ).await;
+/// assert_eq!(out, Either4::Third(42));
+/// #
+/// # });
+/// ```
+///
+/// [`either()`]: crate::either()
+/// [`either3()`]: crate::either3()
+/// [`either12()`]: crate::either12()
+#[macro_export]
+macro_rules! either {
+    ($a:expr, $b:expr $(,)?) => {
+        $crate::either($a, $b)
+    };
+    ($a:expr, $b:expr, $c:expr $(,)?) => {
+        $crate::either3($a, $b, $c)
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr $(,)?) => {
+        $crate::either4($a, $b, $c, $d)
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr $(,)?) => {
+        $crate::either5($a, $b, $c, $d, $e)
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr $(,)?) => {
+        $crate::either6($a, $b, $c, $d, $e, $f)
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr, $g:expr $(,)?) => {
+        $crate::either7($a, $b, $c, $d, $e, $f, $g)
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr, $g:expr, $h:expr $(,)?) => {
+        $crate::either8($a, $b, $c, $d, $e, $f, $g, $h)
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr, $g:expr, $h:expr, $i:expr $(,)?) => {
+        $crate::either9($a, $b, $c, $d, $e, $f, $g, $h, $i)
+    };
+    (
+        $a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr, $g:expr, $h:expr, $i:expr,
+        $j:expr $(,)?
+    ) => {
+        $crate::either10($a, $b, $c, $d, $e, $f, $g, $h, $i, $j)
+    };
+    (
+        $a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr, $g:expr, $h:expr, $i:expr, $j:expr,
+        $k:expr $(,)?
+    ) => {
+        $crate::either11($a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k)
+    };
+    (
+        $a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr, $g:expr, $h:expr, $i:expr, $j:expr,
+        $k:expr, $l:expr $(,)?
+    ) => {
+        $crate::either12($a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l)
+    };
+}
+
+// ======================================== try_either!() ======================================= \\
+
+/// Returns a future polling two to twelve futures and returning a result with the output or error
+/// returned by the first one to complete.
+///
+/// This expands to a call to [`try_either()`] or to the [`try_either3()`] to [`try_either12()`]
+/// function matching the number of futures given, whose output is respectively a result of an
+/// [`Either`] or of one of [`Either3`] to [`Either12`].
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{try_either, Either, Either4};
+///
+/// # future::block_on(async {
+/// #
+/// let out = try_either!(
+///     async { Ok(42) },
+///     async { Result::<bool, bool>::Err(false) },
+/// ).await;
+/// assert_eq!(out, Ok(Either::Left(42)));
+///
+/// let out = try_either!(
+///     future::pending::<Result<bool, i32>>(),
+///     future::pending::<Result<bool, i32>>(),
+///     async { Ok(42) },
+///     async { Result::<bool, i32>::Err(42) },
+/// ).await;
+/// assert_eq!(out, Ok(Either4::Third(42)));
+/// #
+/// # });
+/// ```
+///
+/// [`try_either()`]: crate::try_either()
+/// [`try_either3()`]: crate::try_either3()
+/// [`try_either12()`]: crate::try_either12()
+#[macro_export]
+macro_rules! try_either {
+    ($a:expr, $b:expr $(,)?) => {
+        $crate::try_either($a, $b)
+    };
+    ($a:expr, $b:expr, $c:expr $(,)?) => {
+        $crate::try_either3($a, $b, $c)
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr $(,)?) => {
+        $crate::try_either4($a, $b, $c, $d)
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr $(,)?) => {
+        $crate::try_either5($a, $b, $c, $d, $e)
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr $(,)?) => {
+        $crate::try_either6($a, $b, $c, $d, $e, $f)
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr, $g:expr $(,)?) => {
+        $crate::try_either7($a, $b, $c, $d, $e, $f, $g)
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr, $g:expr, $h:expr $(,)?) => {
+        $crate::try_either8($a, $b, $c, $d, $e, $f, $g, $h)
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr, $g:expr, $h:expr, $i:expr $(,)?) => {
+        $crate::try_either9($a, $b, $c, $d, $e, $f, $g, $h, $i)
+    };
+    (
+        $a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr, $g:expr, $h:expr, $i:expr,
+        $j:expr $(,)?
+    ) => {
+        $crate::try_either10($a, $b, $c, $d, $e, $f, $g, $h, $i, $j)
+    };
+    (
+        $a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr, $g:expr, $h:expr, $i:expr, $j:expr,
+        $k:expr $(,)?
+    ) => {
+        $crate::try_either11($a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k)
+    };
+    (
+        $a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr, $g:expr, $h:expr, $i:expr, $j:expr,
+        $k:expr, $l:expr $(,)?
+    ) => {
+        $crate::try_either12($a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l)
+    };
+}
+
+// ===================================== generate_either!() ===================================== \\
+
+/// Generates an output enum, a [`Future`] and a function racing any number of futures, similarly
+/// to [`either3()`] to [`either12()`].
+///
+/// The enum is given as a list of variants, each with the name of its type parameter and the name
+/// of the function argument (and future field) it corresponds to. The generated enum derives the
+/// same traits as [`Either`], and has an `into_inner()` method when all its variants hold the same
+/// type. The generated future will always poll the futures in the order they were given.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+///
+/// futures_either::generate_either! {
+///     /// The output of `race3()`.
+///     pub enum Race3Output {
+///         Cache(A, cache),
+///         Replica(B, replica),
+///         Primary(C, primary),
+///     }
+///
+///     /// The future returned by `race3()`.
+///     pub struct Race3;
+///
+///     /// Races a cache, a replica, and a primary.
+///     pub fn race3;
+/// }
+///
+/// # future::block_on(async {
+/// #
+/// let out = race3(
+///     future::pending::<bool>(),
+///     async { 42 },
+///     async { false },
+/// ).await;
+/// assert_eq!(out, Race3Output::Replica(42));
+///
+/// let out = race3(async { 42 }, async { 24 }, async { 12 }).await;
+/// assert_eq!(out.into_inner(), 42);
+/// #
+/// # });
+/// ```
+///
+/// [`Future`]: core::future::Future
+/// [`either3()`]: crate::either3()
+/// [`either12()`]: crate::either12()
+#[macro_export]
+macro_rules! generate_either {
+    (@same $_t:ident) => { T };
+    (
+        $(#[$enum_meta:meta])*
+        $enum_vis:vis enum $Enum:ident {
+            $($v:ident($T:ident, $field:ident)),+ $(,)?
+        }
+
+        $(#[$fut_meta:meta])*
+        $fut_vis:vis struct $Fut:ident;
+
+        $(#[$fn_meta:meta])*
+        $fn_vis:vis fn $fn:ident;
+    ) => {
+        $(#[$enum_meta])*
+        #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+        $enum_vis enum $Enum<$($T),+> {
+            $($v($T),)+
+        }
+
+        impl<T> $Enum<$($crate::generate_either!(@same $T)),+> {
+            /// Returns the value, whichever future it is the output of.
+            pub fn into_inner(self) -> T {
+                match self {
+                    $(Self::$v(out) => out,)+
+                }
+            }
+        }
+
+        $crate::__private::pin_project! {
+            $(#[$fut_meta])*
+            $fut_vis struct $Fut<$($T),+> {
+                $(#[pin] $field: $T,)+
+            }
+        }
+
+        $(#[$fn_meta])*
+        #[allow(clippy::too_many_arguments)]
+        $fn_vis fn $fn<$($T),+>($($field: $T),+) -> $Fut<$($T),+>
+        where
+            $($T: ::core::future::Future,)+
+        {
+            $Fut { $($field),+ }
+        }
+
+        impl<$($T),+> ::core::future::Future for $Fut<$($T),+>
+        where
+            $($T: ::core::future::Future,)+
+        {
+            type Output = $Enum<$($T::Output),+>;
+
+            fn poll(
+                self: ::core::pin::Pin<&mut Self>,
+                ctx: &mut ::core::task::Context,
+            ) -> ::core::task::Poll<Self::Output> {
+                let this = self.project();
+
+                $(
+                    let fut = this.$field;
+                    if let ::core::task::Poll::Ready(out) = ::core::future::Future::poll(fut, ctx) {
+                        return ::core::task::Poll::Ready($Enum::$v(out));
+                    }
+                )+
+
+                ::core::task::Poll::Pending
+            }
+        }
+    };
+}
+
+// ======================================== race_array() ======================================== \\
+
+/// Returns a future polling an array of futures and returning the index and output of the first
+/// one to complete.
+///
+/// The returned future will always poll the futures in the order they are in the array. If the
+/// array is empty, it will never complete.
+///
+/// The futures must implement [`Unpin`], so that they can be polled in place without requiring
+/// an allocation; futures which don't can be pinned with [`pin!`] (or [`Box::pin()`]) first.
+///
+/// ## Example
+///
+/// ```rust
+/// use core::pin::pin;
+///
+/// use futures_lite::future;
+/// use futures_either::race_array;
+///
+/// async fn get(out: Option<i32>) -> i32 {
+///     match out {
+///         Some(out) => out,
+///         None => future::pending().await,
+///     }
+/// }
+///
+/// # future::block_on(async {
+/// #
+/// let out = race_array([future::ready(42), future::ready(24)]).await;
+/// assert_eq!(out, (0, 42));
+///
+/// let out = race_array([pin!(get(None)), pin!(get(None)), pin!(get(Some(42)))]).await;
+/// assert_eq!(out, (2, 42));
+/// #
+/// # });
+/// ```
+///
+/// [`pin!`]: core::pin::pin
+/// [`Box::pin()`]: alloc::boxed::Box::pin()
+pub const fn race_array<F, const N: usize>(futs: [F; N]) -> futs::RaceArray<F, N>
+where
+    F: Future + Unpin,
+{
+    futs::RaceArray { futs }
+}
+
+// ========================================= race_iter() ======================================== \\
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+/// Returns a future polling a collection of futures and returning the index and output of the
+/// first one to complete.
+///
+/// The returned future will always poll the futures in the order they were given, and will drop
+/// the remaining ones once one of them completes. If the collection is empty, it will never
+/// complete.
+///
+/// The items of `futs` can be anything implementing [`IntoFuture`], and get converted into
+/// futures right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::race_iter;
+///
+/// async fn get(out: Option<i32>) -> i32 {
+///     match out {
+///         Some(out) => out,
+///         None => future::pending().await,
+///     }
+/// }
+///
+/// # future::block_on(async {
+/// #
+/// let out = race_iter(vec![get(Some(42)), get(Some(24))]).await;
+/// assert_eq!(out, (0, 42));
+///
+/// let out = race_iter((0..8).map(|idx| get(if idx == 5 { Some(42) } else { None }))).await;
+/// assert_eq!(out, (5, 42));
+/// #
+/// # });
+/// ```
+pub fn race_iter<I>(futs: I) -> futs::RaceIter<<I::Item as IntoFuture>::IntoFuture>
+where
+    I: IntoIterator,
+    I::Item: IntoFuture,
+{
+    futs::RaceIter { futs: futs.into_iter().map(|fut| Box::pin(fut.into_future())).collect() }
+}
+
+// ========================================= select_ok() ======================================== \\
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+/// Returns a future polling a collection of fallible futures and returning the index and output
+/// of the first one to succeed, or the errors returned by all of them if none succeeds.
+///
+/// The returned future will always poll the futures in the order they were given, and will drop
+/// each of them as soon as it fails, or all of them once one succeeds. The errors are returned in
+/// the order the futures were given. If the collection is empty, it will directly fail without
+/// any error.
+///
+/// The items of `futs` can be anything implementing [`IntoFuture`], and get converted into
+/// futures right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::select_ok;
+///
+/// async fn get(out: Option<Result<i32, i32>>) -> Result<i32, i32> {
+///     match out {
+///         Some(out) => out,
+///         None => future::pending().await,
+///     }
+/// }
+///
+/// # future::block_on(async {
+/// #
+/// let out = select_ok(vec![get(Some(Err(24))), get(None), get(Some(Ok(42)))]).await;
+/// assert_eq!(out, Ok((2, 42)));
+///
+/// let out = select_ok(vec![get(Some(Err(42))), get(Some(Err(24)))]).await;
+/// assert_eq!(out, Err(vec![42, 24]));
+/// #
+/// # });
+/// ```
+pub fn select_ok<T, E, I, F>(futs: I) -> futs::SelectOk<F::IntoFuture, E>
+where
+    I: IntoIterator<Item = F>,
+    F: IntoFuture<Output = Result<T, E>>,
+{
+    let futs = futs.into_iter().map(|fut| Some(Box::pin(fut.into_future()))).collect::<Vec<_>>();
+    let errs = futs.iter().map(|_| None).collect();
+
+    futs::SelectOk { futs: futs.into_boxed_slice(), errs }
+}
+
+// ========================================== quorum() ========================================== \\
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+/// Returns a future polling a collection of futures and returning the indexes and outputs of the
+/// first `k` ones to complete.
+///
+/// The returned future will always poll the futures in the order they were given, and will drop
+/// the remaining ones once `k` of them have completed. The outputs are returned in the order the
+/// futures completed. If `k` is greater than the number of futures, it will complete once all of
+/// them have.
+///
+/// The items of `futs` can be anything implementing [`IntoFuture`], and get converted into
+/// futures right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::quorum;
+///
+/// async fn get(out: Option<i32>) -> i32 {
+///     match out {
+///         Some(out) => out,
+///         None => future::pending().await,
+///     }
+/// }
+///
+/// # future::block_on(async {
+/// #
+/// let out = quorum(vec![get(None), get(Some(42)), get(Some(24))], 2).await;
+/// assert_eq!(out, vec![(1, 42), (2, 24)]);
+///
+/// let out = quorum(vec![get(Some(42)), get(Some(24))], 3).await;
+/// assert_eq!(out, vec![(0, 42), (1, 24)]);
+/// #
+/// # });
+/// ```
+pub fn quorum<I, F>(futs: I, k: usize) -> futs::Quorum<F::IntoFuture, F::Output>
+where
+    I: IntoIterator<Item = F>,
+    F: IntoFuture,
+{
+    let futs = futs.into_iter().map(|fut| Some(Box::pin(fut.into_future()))).collect::<Vec<_>>();
+    let k = k.min(futs.len());
+
+    futs::Quorum { futs: futs.into_boxed_slice(), outs: Vec::with_capacity(k), k }
+}
+
+// ======================================== all_settled() ======================================= \\
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+/// Returns a future polling a collection of futures until all of them complete and returning
+/// their outputs.
+///
+/// Contrary to the other functions of this crate, the returned future doesn't race the futures:
+/// even if they return errors, they are all polled to completion. The outputs are returned in the
+/// order the futures were given. For two futures of different types, see [`both()`].
+///
+/// The items of `futs` can be anything implementing [`IntoFuture`], and get converted into
+/// futures right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::all_settled;
+///
+/// # future::block_on(async {
+/// #
+/// let out = all_settled(vec![
+///     future::ready(Ok(42)),
+///     future::ready(Err(false)),
+///     future::ready(Ok(24)),
+/// ]).await;
+/// assert_eq!(out, vec![Ok(42), Err(false), Ok(24)]);
+/// #
+/// # });
+/// ```
+pub fn all_settled<I, F>(futs: I) -> futs::AllSettled<F::IntoFuture, F::Output>
+where
+    I: IntoIterator<Item = F>,
+    F: IntoFuture,
+{
+    let futs = futs.into_iter().map(|fut| Some(Box::pin(fut.into_future()))).collect::<Vec<_>>();
+    let outs = futs.iter().map(|_| None).collect();
+
+    futs::AllSettled { futs: futs.into_boxed_slice(), outs }
+}
+
+// ========================================= abortable() ======================================== \\
+
+#[cfg(feature = "abortable")]
+#[cfg_attr(docsrs, doc(cfg(feature = "abortable")))]
+/// Returns a future polling `fut` (e.g. a race) and returning its output, along with a handle
+/// allowing to abort it.
+///
+/// Once [`AbortHandle::abort()`] gets called, the returned future drops `fut` (and with it, the
+/// raced futures) the next time it gets polled, which it gets woken up for, and returns
+/// [`Aborted`]. This allows another task to cancel a race it doesn't own.
+///
+/// `fut` can be anything implementing [`IntoFuture`], and gets converted into a future right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{abortable, either, Aborted};
+///
+/// # future::block_on(async {
+/// #
+/// let (fut, handle) = abortable(either(future::pending::<i32>(), future::pending::<bool>()));
+///
+/// handle.abort();
+/// assert_eq!(fut.await, Err(Aborted));
+/// assert!(handle.is_aborted());
+/// #
+/// # });
+/// ```
+pub fn abortable<F>(fut: F) -> (futs::Abortable<F::IntoFuture>, AbortHandle)
+where
+    F: IntoFuture,
+{
+    let state = Arc::new(AbortState {
+        aborted: AtomicBool::new(false),
+        waker: atomic_waker::AtomicWaker::new(),
+    });
+
+    let handle = AbortHandle { state: state.clone() };
+    (futs::Abortable { fut: Some(fut.into_future()), state }, handle)
+}
+
+#[cfg(feature = "abortable")]
+impl AbortHandle {
+    /// Aborts the future this handle was returned with, waking it up if needed.
+    ///
+    /// This does nothing if the future has already completed.
+    pub fn abort(&self) {
+        self.state.aborted.store(true, Ordering::Release);
+        self.state.waker.wake();
+    }
+
+    /// Returns whether [`abort()`] has been called on this handle (or one of its clones).
+    ///
+    /// [`abort()`]: AbortHandle::abort()
+    pub fn is_aborted(&self) -> bool {
+        self.state.aborted.load(Ordering::Acquire)
+    }
+}
+
+#[cfg(feature = "abortable")]
+impl fmt::Debug for AbortHandle {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("AbortHandle").field("aborted", &self.is_aborted()).finish()
+    }
+}
+
+// ==================================== set_busy_wake_hook() ==================================== \\
+
+/// The hook set with [`set_busy_wake_hook()`], if any.
+#[cfg(all(debug_assertions, feature = "std"))]
+static BUSY_WAKE_HOOK: RwLock<Option<fn(&'static str)>> = RwLock::new(None);
+
+/// How many times in a row a race has to wake itself up while being polled to be reported, as set
+/// with [`set_busy_wake_threshold()`].
+#[cfg(all(debug_assertions, feature = "std"))]
+static BUSY_WAKE_POLLS: AtomicU32 = AtomicU32::new(1 << 20);
+
+/// Makes the races of this crate call `hook` with their type name when they detect that they are
+/// being woken up and polled in a loop.
+///
+/// In debug builds, once a hook has been set, [`either()`], [`either_fair()`], [`try_either()`],
+/// [`try_either_fair()`] and the futures built on them count how many times in a row one of their
+/// futures wakes up the task while being polled, without either of them completing. Past a
+/// million polls (or the number set with [`set_busy_wake_threshold()`]), which usually means that
+/// one of their futures keeps waking up the task without making progress (e.g. by waking its
+/// waker before returning [`Poll::Pending`]), they call the hook, once. Races which are only woken
+/// up from outside of their polls are never reported, however long they last.
+///
+/// Without a hook, or in release builds, nothing gets counted.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_either::set_busy_wake_hook;
+///
+/// set_busy_wake_hook(|race| eprintln!("`{}` is being polled in a loop", race));
+/// ```
+///
+/// [`Poll::Pending`]: core::task::Poll::Pending
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn set_busy_wake_hook(hook: fn(&'static str)) {
+    #[cfg(debug_assertions)]
+    {
+        *BUSY_WAKE_HOOK.write().unwrap_or_else(PoisonError::into_inner) = Some(hook);
+    }
+
+    #[cfg(not(debug_assertions))]
+    let _ = hook;
+}
+
+/// Sets how many times in a row a race has to wake itself up while being polled to call the hook
+/// set with [`set_busy_wake_hook()`], which is a million by default.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_either::{set_busy_wake_hook, set_busy_wake_threshold};
+///
+/// set_busy_wake_threshold(1_000);
+/// set_busy_wake_hook(|race| eprintln!("`{}` is being polled in a loop", race));
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn set_busy_wake_threshold(polls: u32) {
+    #[cfg(debug_assertions)]
+    BUSY_WAKE_POLLS.store(polls, Ordering::Relaxed);
+
+    #[cfg(not(debug_assertions))]
+    let _ = polls;
+}
+
+impl Spins {
+    const fn new() -> Self {
+        Spins {
+            #[cfg(all(debug_assertions, feature = "std"))]
+            polls: 0,
+        }
+    }
+
+    /// Polls the race whose type name is returned by `race` with `poll`, reporting it if its
+    /// futures have woken up the task while being polled too many times in a row without any of
+    /// them completing.
+    fn poll<T>(
+        &mut self,
+        ctx: &mut Context,
+        race: fn() -> &'static str,
+        poll: impl FnOnce(&mut Context) -> Poll<T>,
+    ) -> Poll<T> {
+        #[cfg(all(debug_assertions, feature = "std"))]
+        {
+            let hook = *BUSY_WAKE_HOOK.read().unwrap_or_else(PoisonError::into_inner);
+            if let Some(hook) = hook {
+                let tag = WakeTag::new(ctx.waker());
+                let waker = Waker::from(tag.clone());
+                let out = poll(&mut Context::from_waker(&waker));
+
+                self.polls = if out.is_pending() && tag.woken.load(Ordering::Acquire) {
+                    self.polls.saturating_add(1)
+                } else {
+                    0
+                };
+
+                if self.polls == BUSY_WAKE_POLLS.load(Ordering::Relaxed) {
+                    hook(race());
+                }
+
+                return out;
+            }
+        }
+
+        #[cfg(not(all(debug_assertions, feature = "std")))]
+        let _ = race;
+
+        poll(ctx)
+    }
+}
+
+// =========================================== Select2 ========================================== \\
+
+/// Two slots, each containing a future (or nothing), which can be raced repeatedly, refilling the
+/// slot of the future which completed in between.
+///
+/// Contrary to [`either()`], which gets consumed once one of its futures completes, this allows to
+/// keep the future which didn't complete across the iterations of a loop, without creating it
+/// again. Both futures must implement [`Unpin`] (e.g. by boxing them).
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{Either, Select2};
+///
+/// # future::block_on(async {
+/// #
+/// let mut select = Select2::new(future::ready(1), future::ready(true));
+///
+/// let mut outs = Vec::new();
+/// while let Some(out) = select.select().await {
+///     if let Either::Left(left) = out {
+///         if left < 3 {
+///             select.replace_left(future::ready(left + 1));
+///         }
+///     }
+///
+///     outs.push(out);
+/// }
+///
+/// assert_eq!(outs, [Either::Left(1), Either::Right(true), Either::Left(2), Either::Left(3)]);
+/// #
+/// # });
+/// ```
+pub struct Select2<L, R> {
+    left: Option<L>,
+    right: Option<R>,
+    /// The slot to poll first during the next call to [`select()`], which alternates each time a
+    /// future completes, so that a future which is always ready doesn't starve the other one.
+    ///
+    /// [`select()`]: Select2::select()
+    first: Side,
+}
+
+impl<L, R> Select2<L, R> {
+    /// Returns new slots containing `left` and `right`.
+    pub const fn new(left: L, right: R) -> Self {
+        Select2 { left: Some(left), right: Some(right), first: Side::Left }
+    }
+
+    /// Returns new empty slots.
+    pub const fn empty() -> Self {
+        Select2 { left: None, right: None, first: Side::Left }
+    }
+
+    /// Returns a future polling the futures in the two slots and returning the output of the first
+    /// one to complete, emptying its slot, or `None` if both slots are empty.
+    ///
+    /// The slot polled first alternates each time a future completes. Dropping the returned future
+    /// before it completes leaves both slots as they were.
+    pub fn select(&mut self) -> futs::Select<'_, L, R> {
+        futs::Select { select: self }
+    }
+
+    /// Puts `left` in the left slot, returning the future it contained, if any.
+    pub fn replace_left(&mut self, left: L) -> Option<L> {
+        self.left.replace(left)
+    }
+
+    /// Puts `right` in the right slot, returning the future it contained, if any.
+    pub fn replace_right(&mut self, right: R) -> Option<R> {
+        self.right.replace(right)
+    }
+
+    /// Empties the left slot, returning the future it contained, if any.
+    pub fn take_left(&mut self) -> Option<L> {
+        self.left.take()
+    }
+
+    /// Empties the right slot, returning the future it contained, if any.
+    pub fn take_right(&mut self) -> Option<R> {
+        self.right.take()
+    }
+
+    /// Returns whether both slots are empty.
+    pub fn is_empty(&self) -> bool {
+        self.left.is_none() && self.right.is_none()
+    }
+
+    /// Returns the futures contained in the two slots.
+    pub fn into_inner(self) -> (Option<L>, Option<R>) {
+        (self.left, self.right)
+    }
+}
+
+impl<L, R> Default for Select2<L, R> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<L, R> fmt::Debug for Select2<L, R> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("Select2")
+            .field("left", &self.left.as_ref().map(|_| type_name::<L>()))
+            .field("right", &self.right.as_ref().map(|_| type_name::<R>()))
+            .finish()
+    }
+}
+
+// ======================================== EitherBuilder ======================================= \\
+
+/// A builder allowing to configure how two futures get raced, before awaiting them.
+///
+/// By default, the resulting future behaves like [`either()`], always polling the left future
+/// first. Awaiting the builder (or calling [`into_future()`]) returns a future with a concrete
+/// type, without any dynamic dispatch.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::time::Elapsed;
+/// use futures_either::{Either, EitherBuilder, Side};
+///
+/// # future::block_on(async {
+/// #
+/// let out = EitherBuilder::new(async { 42 }, async { false })
+///     .biased(Side::Right)
+///     .await;
+/// assert_eq!(out, Either::Right(false));
+///
+/// let out = EitherBuilder::new(future::pending::<i32>(), future::pending::<bool>())
+///     .biased(Side::Left)
+///     .timeout(async {})
+///     .await;
+/// assert_eq!(out, Err(Elapsed));
+/// #
+/// # });
+/// ```
+///
+/// [`into_future()`]: core::future::IntoFuture::into_future()
+pub struct EitherBuilder<L, R> {
+    left: L,
+    right: R,
+    priority: Priority,
+    one_per_poll: bool,
+}
+
+impl<L, R> EitherBuilder<L, R> {
+    /// Returns a new builder racing `left` and `right`, polling `left` first.
+    pub const fn new(left: L, right: R) -> Self {
+        EitherBuilder { left, right, priority: Priority::Biased(Side::Left), one_per_poll: false }
+    }
+
+    /// Makes the future always poll the future on `side` first, as [`either()`] does with the
+    /// left future.
+    pub fn biased(mut self, side: Side) -> Self {
+        self.priority = Priority::Biased(side);
+        self
+    }
+
+    #[cfg(feature = "fair-lite")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+    /// Makes the future randomly choose which future to poll first each time it gets polled, as
+    /// [`either_fair()`] does.
+    pub fn fair(mut self) -> Self {
+        self.priority = Priority::Fair;
+        self
+    }
+
+    /// Makes the future poll at most one of the two futures each time it gets polled, to avoid
+    /// doing the work of both in a single call when both are heavy.
+    ///
+    /// The future to poll first is chosen as configured. If it isn't ready, the other one is
+    /// deferred to the next poll and the future wakes itself up before returning
+    /// [`Poll::Pending`], so that the other future still gets polled. If the deferred future
+    /// isn't ready either, the future doesn't wake itself up again: it waits for one of the two
+    /// futures to wake it up, and then starts over from the future to poll first.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use core::future::{Future, IntoFuture};
+    /// use core::pin::pin;
+    /// use core::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// use std::task::{Context, Wake, Waker};
+    ///
+    /// use futures_lite::future;
+    /// use futures_either::{Either, EitherBuilder};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let mut fut = EitherBuilder::new(future::pending::<i32>(), future::ready(false))
+    ///     .one_per_poll()
+    ///     .into_future();
+    ///
+    /// assert_eq!(future::poll_once(&mut fut).await, None);
+    /// assert_eq!(future::poll_once(&mut fut).await, Some(Either::Right(false)));
+    /// #
+    /// # });
+    ///
+    /// struct Wakes(AtomicUsize);
+    ///
+    /// impl Wake for Wakes {
+    ///     fn wake(self: Arc<Self>) {
+    ///         self.0.fetch_add(1, Ordering::Relaxed);
+    ///     }
+    /// }
+    ///
+    /// let wakes = Arc::new(Wakes(AtomicUsize::new(0)));
+    /// let waker = Waker::from(wakes.clone());
+    /// let mut ctx = Context::from_waker(&waker);
+    ///
+    /// let mut fut = pin!(EitherBuilder::new(future::pending::<i32>(), future::pending::<bool>())
+    ///     .one_per_poll()
+    ///     .into_future());
+    ///
+    /// // Polls the left future and wakes the task up to poll the right one...
+    /// assert!(fut.as_mut().poll(&mut ctx).is_pending());
+    /// assert_eq!(wakes.0.load(Ordering::Relaxed), 1);
+    ///
+    /// // ...but doesn't wake it up again once both have been polled.
+    /// assert!(fut.as_mut().poll(&mut ctx).is_pending());
+    /// assert_eq!(wakes.0.load(Ordering::Relaxed), 1);
+    /// ```
+    ///
+    /// [`Poll::Pending`]: core::task::Poll::Pending
+    pub fn one_per_poll(mut self) -> Self {
+        self.one_per_poll = true;
+        self
+    }
+
+    /// Returns a future racing the two futures as configured, but returning [`Elapsed`] if `delay`
+    /// completes before either of them.
+    ///
+    /// The two futures get polled before `delay`. This must be the last method called on the
+    /// builder.
+    ///
+    /// [`Elapsed`]: time::Elapsed
+    pub fn timeout<D>(self, delay: D) -> futs::Timeout<futs::Built<L, R>, D>
+    where
+        L: Future,
+        R: Future,
+        D: Future,
+    {
+        futs::Timeout { fut: self.into_future(), delay }
+    }
+}
+
+impl<L, R> IntoFuture for EitherBuilder<L, R>
+where
+    L: Future,
+    R: Future,
+{
+    type Output = Either<L::Output, R::Output>;
+    type IntoFuture = futs::Built<L, R>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        futs::Built {
+            left: self.left,
+            right: self.right,
+            priority: self.priority,
+            one_per_poll: self.one_per_poll,
+            deferred: None,
+        }
+    }
+}
+
+// ========================================== FutureExt ========================================= \\
+
+/// An extension trait for [`Future`]s, providing methods to race them and to adapt the output of
+/// races.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{Either, Either3, FutureExt};
+///
+/// # future::block_on(async {
+/// #
+/// let out = async { 42 }.or(async { false }).await;
+/// assert_eq!(out, Either::Left(42));
+///
+/// let out = future::pending::<bool>()
+///     .or(future::pending::<bool>())
+///     .or(async { 42 })
+///     .await;
+/// assert_eq!(out, Either3::Third(42));
+/// #
+/// # });
+/// ```
+///
+/// [`Future`]: core::future::Future
+pub trait FutureExt: Future {
+    /// Returns a future polling this future and `right` and returning the output of the first one
+    /// to complete, as [`either()`].
+    ///
+    /// Calling this method on the future it returns (or on the futures returned by
+    /// [`either3()`] to [`either11()`]) will return a future polling all the futures, as
+    /// [`either3()`] to [`either12()`], instead of nesting them.
+    fn or<R>(self, right: R) -> futs::Either<Self, R>
+    where
+        Self: Sized,
+        R: Future,
+    {
+        either(self, right)
+    }
+
+    #[cfg(feature = "fair-lite")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+    /// Returns a future polling this future and `right` and returning the output of the first one
+    /// to complete, as [`either_fair()`].
+    ///
+    /// Contrary to [`or()`], calling this method multiple times will nest the outputs.
+    ///
+    /// [`or()`]: FutureExt::or()
+    fn or_fair<R>(self, right: R) -> futs::EitherFair<Self, R>
+    where
+        Self: Sized,
+        R: Future,
+    {
+        either_fair(self, right)
+    }
+
+    /// Returns a future polling this future and `right` and returning a result with the output or
+    /// error returned by the first one to complete, as [`try_either()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{Either, FutureExt};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = future::pending::<Result<bool, i32>>()
+    ///     .try_or(async { Result::<i32, i32>::Err(42) })
+    ///     .await;
+    /// assert_eq!(out, Err(42));
+    /// #
+    /// # });
+    /// ```
+    fn try_or<OL, OR, E, R>(self, right: R) -> futs::TryEither<Self, R>
+    where
+        Self: Sized + Future<Output = Result<OL, E>>,
+        R: Future<Output = Result<OR, E>>,
+    {
+        try_either(self, right)
+    }
+
+    #[cfg(feature = "fair-lite")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+    /// Returns a future polling this future and `right` and returning a result with the output or
+    /// error returned by the first one to complete, as [`try_either_fair()`].
+    fn try_or_fair<OL, OR, E, R>(self, right: R) -> futs::TryEitherFair<Self, R>
+    where
+        Self: Sized + Future<Output = Result<OL, E>>,
+        R: Future<Output = Result<OR, E>>,
+    {
+        try_either_fair(self, right)
+    }
+
+    /// Returns a future polling this future until it completes with a left or right future, and
+    /// then polling that future until it completes, returning its output on the same side.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either, Either, FutureExt};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either(
+    ///     async { future::ready(42) },
+    ///     future::pending::<future::Ready<bool>>(),
+    /// ).flatten_output().await;
+    /// assert_eq!(out, Either::Left(42));
+    /// #
+    /// # });
+    /// ```
+    fn flatten_output<FL, FR>(self) -> futs::FlattenOutput<Self, FL, FR>
+    where
+        Self: Sized + Future<Output = Either<FL, FR>>,
+        FL: Future,
+        FR: Future,
+    {
+        futs::FlattenOutput { fut: self, inner: None }
+    }
+
+    /// Returns a future polling this future, and then following `policy` when polled again after
+    /// having completed, instead of polling this future again.
+    ///
+    /// This future gets dropped as soon as it completes. See the [`after`] module for the
+    /// available policies.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,should_panic
+    /// use futures_lite::future;
+    /// use futures_either::after::Panic;
+    /// use futures_either::{either, FutureExt};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let mut fut = either(future::ready(42), future::ready(false)).after_ready(Panic);
+    /// (&mut fut).await;
+    /// (&mut fut).await; // panics with "future polled after completion"
+    /// #
+    /// # });
+    /// ```
+    ///
+    /// [`after`]: crate::after
+    fn after_ready<P>(self, policy: P) -> futs::AfterReady<Self, P>
+    where
+        Self: Sized,
+        P: AfterReady<Self::Output>,
+    {
+        futs::AfterReady { fut: Some(self), policy }
+    }
+
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    /// Returns a future polling this future and storing its output once it completes, so that
+    /// polling it again returns a clone of the output.
+    ///
+    /// The returned future can also be cloned, with all the clones sharing this future and its
+    /// output, so that a race can be awaited from multiple places. This future gets polled by
+    /// whichever clone gets polled, and dropped as soon as it completes.
+    ///
+    /// The clones can be sent to other threads if this future and its output can. Clones which
+    /// returned [`Poll::Pending`] (including clones polled while another one is polling this
+    /// future) get woken up once the output is available, as long as the other clones either keep
+    /// getting polled when woken up or get dropped.
+    ///
+    /// See [`FutureExt::cache_local()`] for a version which doesn't require the `std` feature.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use std::thread;
+    ///
+    /// use futures_lite::future;
+    /// use futures_either::{either, Either, FutureExt};
+    ///
+    /// let fut = either(future::ready(42), future::ready(false)).cache();
+    /// let other = fut.clone();
+    ///
+    /// let handle = thread::spawn(move || future::block_on(other));
+    /// assert_eq!(future::block_on(fut), Either::Left(42));
+    /// assert_eq!(handle.join().unwrap(), Either::Left(42));
+    /// ```
+    ///
+    /// [`Poll::Pending`]: core::task::Poll::Pending
+    fn cache(self) -> futs::Cache<Self, Self::Output>
+    where
+        Self: Sized,
+        Self::Output: Clone,
+    {
+        futs::Cache { state: Arc::new(Mutex::new(CacheState::new(self))) }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    /// Returns a future polling this future and storing its output once it completes, as
+    /// `FutureExt::cache()` does, but whose clones can't be sent to other threads.
+    ///
+    /// This doesn't require the `std` feature. A clone polled from within this future returns
+    /// [`Poll::Pending`] (instead of panicking), and gets woken up once the output is available.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use core::cell::RefCell;
+    /// use core::future::Future;
+    /// use core::pin::Pin;
+    /// use core::task::Poll;
+    /// use std::rc::Rc;
+    ///
+    /// use futures_lite::future;
+    /// use futures_either::FutureExt;
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let slot = Rc::new(RefCell::new(None::<Pin<Box<dyn Future<Output = i32>>>>));
+    /// let inner = slot.clone();
+    ///
+    /// let fut = future::poll_fn(move |ctx| {
+    ///     let mut this = inner.borrow_mut().take().expect("should have been set");
+    ///     assert!(this.as_mut().poll(ctx).is_pending());
+    ///     Poll::Ready(42)
+    /// })
+    /// .cache_local();
+    ///
+    /// *slot.borrow_mut() = Some(Box::pin(fut.clone()));
+    /// assert_eq!(fut.await, 42);
+    /// #
+    /// # });
+    /// ```
+    ///
+    /// [`Poll::Pending`]: core::task::Poll::Pending
+    fn cache_local(self) -> futs::CacheLocal<Self, Self::Output>
+    where
+        Self: Sized,
+        Self::Output: Clone,
+    {
+        futs::CacheLocal { state: Rc::new(RefCell::new(CacheState::new(self))) }
+    }
+}
+
+impl<F> FutureExt for F
+where
+    F: Future + ?Sized,
+{
+}
+
+// ========================================== Adapters ========================================== \\
+
+impl<L, R> futs::Either<L, R> {
+    /// Returns the same future as [`either()`], but taking futures directly, which allows to use it
+    /// in const contexts.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use core::future::Future;
+    /// use core::pin::Pin;
+    /// use core::task::{Context, Poll};
+    /// use futures_either::{futs, Either};
+    ///
+    /// struct Ready(i32);
+    ///
+    /// impl Future for Ready {
+    ///     type Output = i32;
+    ///
+    ///     fn poll(self: Pin<&mut Self>, _: &mut Context) -> Poll<i32> {
+    ///         Poll::Ready(self.0)
+    ///     }
+    /// }
+    ///
+    /// const RACE: futs::Either<Ready, Ready> = futs::Either::new(Ready(42), Ready(24));
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// #
+    /// assert_eq!(RACE.await, Either::Left(42));
+    /// #
+    /// # });
+    /// ```
+    pub const fn new(left: L, right: R) -> Self {
+        futs::Either { left, right, done: false, spins: Spins::new() }
+    }
+
+    /// Returns the two futures, without polling them any further.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::either;
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let (left, right) = either(async { 42 }, async { false }).into_inner();
+    /// assert_eq!(left.await, 42);
+    /// assert_eq!(right.await, false);
+    /// #
+    /// # });
+    /// ```
+    pub fn into_inner(self) -> (L, R) {
+        (self.left, self.right)
+    }
+
+    /// Returns the left future, dropping the right one.
+    pub fn left(self) -> L {
+        self.left
+    }
+
+    /// Returns the right future, dropping the left one.
+    pub fn right(self) -> R {
+        self.right
+    }
+
+    /// Returns a mutable reference to the left future.
+    ///
+    /// This requires the future not to be pinned (or to be [`Unpin`]), thus it is usually called
+    /// before awaiting it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either, Either};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let mut fut = either(future::ready(24), future::pending::<bool>());
+    /// *fut.as_left_mut() = future::ready(42);
+    /// assert_eq!(fut.await, Either::Left(42));
+    /// #
+    /// # });
+    /// ```
+    pub fn as_left_mut(&mut self) -> &mut L {
+        &mut self.left
+    }
+
+    /// Returns a mutable reference to the right future.
+    ///
+    /// This requires the future not to be pinned (or to be [`Unpin`]), thus it is usually called
+    /// before awaiting it.
+    pub fn as_right_mut(&mut self) -> &mut R {
+        &mut self.right
+    }
+
+    /// Returns pinned mutable references to the two futures, allowing to poll them separately.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use core::pin::pin;
+    /// use core::task::Poll;
+    ///
+    /// use futures_lite::future;
+    /// use futures_either::either;
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let mut fut = pin!(either(async { 42 }, future::pending::<bool>()));
+    /// let (left, right) = fut.as_mut().as_pin_mut();
+    /// assert_eq!(future::poll_once(left).await, Some(42));
+    /// assert_eq!(future::poll_once(right).await, None);
+    /// #
+    /// # });
+    /// ```
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> (Pin<&mut L>, Pin<&mut R>) {
+        let this = self.project();
+        (this.left, this.right)
+    }
+
+    /// Returns a future polling the two futures in the same order as this one, but returning
+    /// their outputs with their sides swapped.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either, Either};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either(async { 42 }, async { false }).swap().await;
+    /// assert_eq!(out, Either::Right(42));
+    /// #
+    /// # });
+    /// ```
+    pub fn swap(self) -> futs::Swap<Self> {
+        futs::Swap { fut: self }
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<L, R> futs::EitherFair<L, R> {
+    /// Returns the same future as [`either_fair()`], but taking futures directly, which allows to
+    /// use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::EitherFair { left, right, state: FairState::new(), done: false, spins: Spins::new() }
+    }
+
+    /// Returns the two futures, without polling them any further.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::either_fair;
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let (left, right) = either_fair(async { 42 }, async { false }).into_inner();
+    /// assert_eq!(left.await, 42);
+    /// assert_eq!(right.await, false);
+    /// #
+    /// # });
+    /// ```
+    pub fn into_inner(self) -> (L, R) {
+        (self.left, self.right)
+    }
+
+    /// Returns the left future, dropping the right one.
+    pub fn left(self) -> L {
+        self.left
+    }
+
+    /// Returns the right future, dropping the left one.
+    pub fn right(self) -> R {
+        self.right
+    }
+
+    /// Returns a mutable reference to the left future.
+    ///
+    /// This requires the future not to be pinned (or to be [`Unpin`]), thus it is usually called
+    /// before awaiting it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either_fair, Either};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let mut fut = either_fair(future::ready(24), future::pending::<bool>());
+    /// *fut.as_left_mut() = future::ready(42);
+    /// assert_eq!(fut.await, Either::Left(42));
+    /// #
+    /// # });
+    /// ```
+    pub fn as_left_mut(&mut self) -> &mut L {
+        &mut self.left
+    }
+
+    /// Returns a mutable reference to the right future.
+    ///
+    /// This requires the future not to be pinned (or to be [`Unpin`]), thus it is usually called
+    /// before awaiting it.
+    pub fn as_right_mut(&mut self) -> &mut R {
+        &mut self.right
+    }
+
+    /// Returns pinned mutable references to the two futures, allowing to poll them separately.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use core::pin::pin;
+    /// use core::task::Poll;
+    ///
+    /// use futures_lite::future;
+    /// use futures_either::either_fair;
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let mut fut = pin!(either_fair(async { 42 }, future::pending::<bool>()));
+    /// let (left, right) = fut.as_mut().as_pin_mut();
+    /// assert_eq!(future::poll_once(left).await, Some(42));
+    /// assert_eq!(future::poll_once(right).await, None);
+    /// #
+    /// # });
+    /// ```
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> (Pin<&mut L>, Pin<&mut R>) {
+        let this = self.project();
+        (this.left, this.right)
+    }
+
+    /// Returns a future polling the two futures in the same order as this one, but returning
+    /// their outputs with their sides swapped.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either_fair, Either};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either_fair(async { 42 }, future::pending::<bool>()).swap().await;
+    /// assert_eq!(out, Either::Right(42));
+    /// #
+    /// # });
+    /// ```
+    pub fn swap(self) -> futs::Swap<Self> {
+        futs::Swap { fut: self }
+    }
+}
+
+impl<L, R> futs::TryEither<L, R> {
+    /// Returns the same future as [`try_either()`], but taking futures directly, which allows to
+    /// use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::TryEither { left, right, done: false, spins: Spins::new() }
+    }
+
+    /// Returns a future polling the two futures in the same order as this one, but returning
+    /// their outputs with their sides swapped.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{try_either, Either};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = try_either(async { Ok::<_, ()>(42) }, future::pending::<Result<bool, ()>>())
+    ///     .swap()
+    ///     .await;
+    /// assert_eq!(out, Ok(Either::Right(42)));
+    /// #
+    /// # });
+    /// ```
+    pub fn swap(self) -> futs::TrySwap<Self> {
+        futs::TrySwap { fut: self }
+    }
+
+    /// Returns pinned mutable references to the two futures, allowing to poll them separately.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use core::pin::pin;
+    /// use core::task::Poll;
+    ///
+    /// use futures_lite::future;
+    /// use futures_either::try_either;
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let mut fut = pin!(try_either(
+    ///     async { Ok::<_, ()>(42) },
+    ///     future::pending::<Result<bool, ()>>(),
+    /// ));
+    /// let (left, right) = fut.as_mut().as_pin_mut();
+    /// assert_eq!(future::poll_once(left).await, Some(Ok(42)));
+    /// assert_eq!(future::poll_once(right).await, None);
+    /// #
+    /// # });
+    /// ```
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> (Pin<&mut L>, Pin<&mut R>) {
+        let this = self.project();
+        (this.left, this.right)
+    }
+
+    /// Returns a future mapping the successful output of this future with `map`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::try_either;
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = try_either(async { Ok::<_, ()>(42) }, future::pending::<Result<i32, ()>>())
+    ///     .map_ok(|out| out.into_inner() * 2)
+    ///     .await;
+    /// assert_eq!(out, Ok(84));
+    /// #
+    /// # });
+    /// ```
+    pub fn map_ok<OL, OR, E, T, M>(self, map: M) -> futs::MapOk<Self, M>
+    where
+        L: Future<Output = Result<OL, E>>,
+        R: Future<Output = Result<OR, E>>,
+        M: FnOnce(Either<OL, OR>) -> T,
+    {
+        futs::MapOk { fut: self, map: Some(map) }
+    }
+
+    /// Returns a future mapping the error output of this future with `map`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::try_either;
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = try_either(async { Err::<bool, _>(42) }, future::pending::<Result<bool, _>>())
+    ///     .map_err(|err| err * 2)
+    ///     .await;
+    /// assert_eq!(out, Err(84));
+    /// #
+    /// # });
+    /// ```
+    pub fn map_err<OL, OR, E, T, M>(self, map: M) -> futs::MapErr<Self, M>
+    where
+        L: Future<Output = Result<OL, E>>,
+        R: Future<Output = Result<OR, E>>,
+        M: FnOnce(E) -> T,
+    {
+        futs::MapErr { fut: self, map: Some(map) }
+    }
+
+    /// Returns a future passing the successful output of this future to `then`, and then polling
+    /// the future it returns to completion.
+    ///
+    /// If this future fails, its error is returned without calling `then`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::try_either;
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = try_either(async { Ok::<_, ()>(42) }, future::pending::<Result<i32, ()>>())
+    ///     .and_then(|out| async move { Ok(out.into_inner() * 2) })
+    ///     .await;
+    /// assert_eq!(out, Ok(84));
+    /// #
+    /// # });
+    /// ```
+    pub fn and_then<OL, OR, E, T, Fut, M>(self, then: M) -> futs::AndThen<Self, Fut, M>
+    where
+        L: Future<Output = Result<OL, E>>,
+        R: Future<Output = Result<OR, E>>,
+        Fut: Future<Output = Result<T, E>>,
+        M: FnOnce(Either<OL, OR>) -> Fut,
+    {
+        futs::AndThen { fut: self, then: None, map: Some(then) }
+    }
+
+    /// Returns a future calling `inspect` with a reference to the successful output of this
+    /// future, before returning it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{try_either, Either};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let mut seen = None;
+    /// let out = try_either(async { Ok::<_, ()>(42) }, future::pending::<Result<bool, ()>>())
+    ///     .inspect_ok(|out| seen = out.clone().left())
+    ///     .await;
+    /// assert_eq!(out, Ok(Either::Left(42)));
+    /// assert_eq!(seen, Some(42));
+    /// #
+    /// # });
+    /// ```
+    pub fn inspect_ok<OL, OR, E, I>(self, inspect: I) -> futs::InspectOk<Self, I>
+    where
+        L: Future<Output = Result<OL, E>>,
+        R: Future<Output = Result<OR, E>>,
+        I: FnOnce(&Either<OL, OR>),
+    {
+        futs::InspectOk { fut: self, inspect: Some(inspect) }
+    }
+
+    /// Returns a future calling `inspect` with a reference to the error output of this future,
+    /// before returning it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::try_either;
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let mut seen = None;
+    /// let out = try_either(async { Err::<bool, _>(42) }, future::pending::<Result<bool, _>>())
+    ///     .inspect_err(|err| seen = Some(*err))
+    ///     .await;
+    /// assert_eq!(out, Err(42));
+    /// assert_eq!(seen, Some(42));
+    /// #
+    /// # });
+    /// ```
+    pub fn inspect_err<OL, OR, E, I>(self, inspect: I) -> futs::InspectErr<Self, I>
+    where
+        L: Future<Output = Result<OL, E>>,
+        R: Future<Output = Result<OR, E>>,
+        I: FnOnce(&E),
+    {
+        futs::InspectErr { fut: self, inspect: Some(inspect) }
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<L, R> futs::TryEitherFair<L, R> {
+    /// Returns the same future as [`try_either_fair()`], but taking futures directly, which allows
+    /// to use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::TryEitherFair {
+            left,
+            right,
+            state: FairState::new(),
+            done: false,
+            spins: Spins::new(),
+        }
+    }
+
+    /// Returns a future polling the two futures in the same order as this one, but returning
+    /// their outputs with their sides swapped.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{try_either_fair, Either};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = try_either_fair(async { Ok::<_, ()>(42) }, future::pending::<Result<bool, ()>>())
+    ///     .swap()
+    ///     .await;
+    /// assert_eq!(out, Ok(Either::Right(42)));
+    /// #
+    /// # });
+    /// ```
+    pub fn swap(self) -> futs::TrySwap<Self> {
+        futs::TrySwap { fut: self }
+    }
+
+    /// Returns pinned mutable references to the two futures, allowing to poll them separately.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use core::pin::pin;
+    /// use core::task::Poll;
+    ///
+    /// use futures_lite::future;
+    /// use futures_either::try_either_fair;
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let mut fut = pin!(try_either_fair(
+    ///     async { Ok::<_, ()>(42) },
+    ///     future::pending::<Result<bool, ()>>(),
+    /// ));
+    /// let (left, right) = fut.as_mut().as_pin_mut();
+    /// assert_eq!(future::poll_once(left).await, Some(Ok(42)));
+    /// assert_eq!(future::poll_once(right).await, None);
+    /// #
+    /// # });
+    /// ```
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> (Pin<&mut L>, Pin<&mut R>) {
+        let this = self.project();
+        (this.left, this.right)
+    }
+
+    /// Returns a future mapping the successful output of this future with `map`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::try_either_fair;
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = try_either_fair(async { Ok::<_, ()>(42) }, future::pending::<Result<i32, ()>>())
+    ///     .map_ok(|out| out.into_inner() * 2)
+    ///     .await;
+    /// assert_eq!(out, Ok(84));
+    /// #
+    /// # });
+    /// ```
+    pub fn map_ok<OL, OR, E, T, M>(self, map: M) -> futs::MapOk<Self, M>
+    where
+        L: Future<Output = Result<OL, E>>,
+        R: Future<Output = Result<OR, E>>,
+        M: FnOnce(Either<OL, OR>) -> T,
+    {
+        futs::MapOk { fut: self, map: Some(map) }
+    }
+
+    /// Returns a future mapping the error output of this future with `map`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::try_either_fair;
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = try_either_fair(
+    ///     async { Err::<bool, _>(42) },
+    ///     future::pending::<Result<bool, _>>(),
+    /// ).map_err(|err| err * 2).await;
+    /// assert_eq!(out, Err(84));
+    /// #
+    /// # });
+    /// ```
+    pub fn map_err<OL, OR, E, T, M>(self, map: M) -> futs::MapErr<Self, M>
+    where
+        L: Future<Output = Result<OL, E>>,
+        R: Future<Output = Result<OR, E>>,
+        M: FnOnce(E) -> T,
+    {
+        futs::MapErr { fut: self, map: Some(map) }
+    }
+
+    /// Returns a future passing the successful output of this future to `then`, and then polling
+    /// the future it returns to completion.
+    ///
+    /// If this future fails, its error is returned without calling `then`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::try_either_fair;
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = try_either_fair(async { Ok::<_, ()>(42) }, future::pending::<Result<i32, ()>>())
+    ///     .and_then(|out| async move { Ok(out.into_inner() * 2) })
+    ///     .await;
+    /// assert_eq!(out, Ok(84));
+    /// #
+    /// # });
+    /// ```
+    pub fn and_then<OL, OR, E, T, Fut, M>(self, then: M) -> futs::AndThen<Self, Fut, M>
+    where
+        L: Future<Output = Result<OL, E>>,
+        R: Future<Output = Result<OR, E>>,
+        Fut: Future<Output = Result<T, E>>,
+        M: FnOnce(Either<OL, OR>) -> Fut,
+    {
+        futs::AndThen { fut: self, then: None, map: Some(then) }
+    }
+
+    /// Returns a future calling `inspect` with a reference to the successful output of this
+    /// future, before returning it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{try_either_fair, Either};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let mut seen = None;
+    /// let out = try_either_fair(async { Ok::<_, ()>(42) }, future::pending::<Result<bool, ()>>())
+    ///     .inspect_ok(|out| seen = out.clone().left())
+    ///     .await;
+    /// assert_eq!(out, Ok(Either::Left(42)));
+    /// assert_eq!(seen, Some(42));
+    /// #
+    /// # });
+    /// ```
+    pub fn inspect_ok<OL, OR, E, I>(self, inspect: I) -> futs::InspectOk<Self, I>
+    where
+        L: Future<Output = Result<OL, E>>,
+        R: Future<Output = Result<OR, E>>,
+        I: FnOnce(&Either<OL, OR>),
+    {
+        futs::InspectOk { fut: self, inspect: Some(inspect) }
+    }
+
+    /// Returns a future calling `inspect` with a reference to the error output of this future,
+    /// before returning it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::try_either_fair;
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let mut seen = None;
+    /// let out = try_either_fair(
+    ///     async { Err::<bool, _>(42) },
+    ///     future::pending::<Result<bool, _>>(),
+    /// ).inspect_err(|err| seen = Some(*err)).await;
+    /// assert_eq!(out, Err(42));
+    /// assert_eq!(seen, Some(42));
+    /// #
+    /// # });
+    /// ```
+    pub fn inspect_err<OL, OR, E, I>(self, inspect: I) -> futs::InspectErr<Self, I>
+    where
+        L: Future<Output = Result<OL, E>>,
+        R: Future<Output = Result<OR, E>>,
+        I: FnOnce(&E),
+    {
+        futs::InspectErr { fut: self, inspect: Some(inspect) }
+    }
+}
+
+impl<L, R> futs::Either<L, R> {
+    /// Returns a future returning the output of the first future to complete, converted into `T`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::either;
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either(
+    ///     async { 42i32 },
+    ///     async { 24u8 },
+    /// ).unify::<i64>().await;
+    /// assert_eq!(out, 42);
+    /// #
+    /// # });
+    /// ```
+    pub fn unify<T>(self) -> futs::Unify<Self, T>
+    where
+        L: Future,
+        R: Future,
+        L::Output: Into<T>,
+        R::Output: Into<T>,
+    {
+        futs::Unify { fut: self, _out: PhantomData }
+    }
+
+    /// Returns a future polling the futures of this one and `third`, and returning the output of
+    /// the first one to complete, as [`either3()`].
+    ///
+    /// This takes precedence over [`FutureExt::or()`], so that chained calls don't result in
+    /// nested outputs.
+    pub fn or<C>(self, third: C) -> futs::Either3<L, R, C> {
+        futs::Either3 { first: self.left, second: self.right, third }
+    }
+
+    /// Returns a future mapping the left output of this future with `map`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either, Either};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either(async { 42 }, future::pending::<bool>())
+    ///     .map_left(|left| left * 2)
+    ///     .await;
+    /// assert_eq!(out, Either::Left(84));
+    /// #
+    /// # });
+    /// ```
+    pub fn map_left<T, M>(self, map: M) -> futs::MapLeft<Self, M>
+    where
+        L: Future,
+        R: Future,
+        M: FnOnce(L::Output) -> T,
+    {
+        futs::MapLeft { fut: self, map: Some(map) }
+    }
+
+    /// Returns a future mapping the right output of this future with `map`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either, Either};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either(future::pending::<bool>(), async { 42 })
+    ///     .map_right(|right| right * 2)
+    ///     .await;
+    /// assert_eq!(out, Either::Right(84));
+    /// #
+    /// # });
+    /// ```
+    pub fn map_right<T, M>(self, map: M) -> futs::MapRight<Self, M>
+    where
+        L: Future,
+        R: Future,
+        M: FnOnce(R::Output) -> T,
+    {
+        futs::MapRight { fut: self, map: Some(map) }
+    }
+
+    /// Returns a future mapping the left output of this future with `map_left`, or its right
+    /// output with `map_right`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either, Either};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either(async { 42 }, future::pending::<bool>())
+    ///     .map_either(|left| left * 2, |right| !right)
+    ///     .await;
+    /// assert_eq!(out, Either::Left(84));
+    /// #
+    /// # });
+    /// ```
+    pub fn map_either<TL, TR, ML, MR>(
+        self,
+        map_left: ML,
+        map_right: MR,
+    ) -> futs::MapEither<Self, ML, MR>
+    where
+        L: Future,
+        R: Future,
+        ML: FnOnce(L::Output) -> TL,
+        MR: FnOnce(R::Output) -> TR,
+    {
+        futs::MapEither { fut: self, map_left: Some(map_left), map_right: Some(map_right) }
+    }
+
+    /// Returns a future calling `inspect` with a reference to the left output of this future,
+    /// before returning it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either, Either};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let mut seen = None;
+    /// let out = either(async { 42 }, future::pending::<bool>())
+    ///     .inspect_left(|left| seen = Some(*left))
+    ///     .await;
+    /// assert_eq!(out, Either::Left(42));
+    /// assert_eq!(seen, Some(42));
+    /// #
+    /// # });
+    /// ```
+    pub fn inspect_left<I>(self, inspect: I) -> futs::InspectLeft<Self, I>
+    where
+        L: Future,
+        R: Future,
+        I: FnOnce(&L::Output),
+    {
+        futs::InspectLeft { fut: self, inspect: Some(inspect) }
+    }
+
+    /// Returns a future calling `inspect` with a reference to the right output of this future,
+    /// before returning it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either, Either};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let mut seen = None;
+    /// let out = either(future::pending::<bool>(), async { 42 })
+    ///     .inspect_right(|right| seen = Some(*right))
+    ///     .await;
+    /// assert_eq!(out, Either::Right(42));
+    /// assert_eq!(seen, Some(42));
+    /// #
+    /// # });
+    /// ```
+    pub fn inspect_right<I>(self, inspect: I) -> futs::InspectRight<Self, I>
+    where
+        L: Future,
+        R: Future,
+        I: FnOnce(&R::Output),
+    {
+        futs::InspectRight { fut: self, inspect: Some(inspect) }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    /// Returns this future, boxed and with its type erased.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either, BoxEither, Either};
+    ///
+    /// fn race() -> BoxEither<'static, i32, bool> {
+    ///     either(async { 42 }, future::pending()).boxed()
+    /// }
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// assert_eq!(race().await, Either::Left(42));
+    /// #
+    /// # });
+    /// ```
+    pub fn boxed<'a>(self) -> BoxEither<'a, L::Output, R::Output>
+    where
+        L: Future + Send + 'a,
+        R: Future + Send + 'a,
+    {
+        Box::pin(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    /// Returns this future, boxed and with its type erased, without requiring it to be [`Send`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use futures_lite::future;
+    /// use futures_either::{either, Either, LocalBoxEither};
+    ///
+    /// fn race() -> LocalBoxEither<'static, Rc<i32>, bool> {
+    ///     either(async { Rc::new(42) }, future::pending()).boxed_local()
+    /// }
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// assert_eq!(race().await, Either::Left(Rc::new(42)));
+    /// #
+    /// # });
+    /// ```
+    pub fn boxed_local<'a>(self) -> LocalBoxEither<'a, L::Output, R::Output>
+    where
+        L: Future + 'a,
+        R: Future + 'a,
+    {
+        Box::pin(self)
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<L, R> futs::EitherFair<L, R> {
+    /// Returns a future mapping the left output of this future with `map`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either_fair, Either};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either_fair(async { 42 }, future::pending::<bool>())
+    ///     .map_left(|left| left * 2)
+    ///     .await;
+    /// assert_eq!(out, Either::Left(84));
+    /// #
+    /// # });
+    /// ```
+    pub fn map_left<T, M>(self, map: M) -> futs::MapLeft<Self, M>
+    where
+        L: Future,
+        R: Future,
+        M: FnOnce(L::Output) -> T,
+    {
+        futs::MapLeft { fut: self, map: Some(map) }
+    }
+
+    /// Returns a future mapping the right output of this future with `map`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either_fair, Either};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either_fair(future::pending::<bool>(), async { 42 })
+    ///     .map_right(|right| right * 2)
+    ///     .await;
+    /// assert_eq!(out, Either::Right(84));
+    /// #
+    /// # });
+    /// ```
+    pub fn map_right<T, M>(self, map: M) -> futs::MapRight<Self, M>
+    where
+        L: Future,
+        R: Future,
+        M: FnOnce(R::Output) -> T,
+    {
+        futs::MapRight { fut: self, map: Some(map) }
+    }
+
+    /// Returns a future mapping the left output of this future with `map_left`, or its right
+    /// output with `map_right`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either_fair, Either};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either_fair(async { 42 }, future::pending::<bool>())
+    ///     .map_either(|left| left * 2, |right| !right)
+    ///     .await;
+    /// assert_eq!(out, Either::Left(84));
+    /// #
+    /// # });
+    /// ```
+    pub fn map_either<TL, TR, ML, MR>(
+        self,
+        map_left: ML,
+        map_right: MR,
+    ) -> futs::MapEither<Self, ML, MR>
+    where
+        L: Future,
+        R: Future,
+        ML: FnOnce(L::Output) -> TL,
+        MR: FnOnce(R::Output) -> TR,
+    {
+        futs::MapEither { fut: self, map_left: Some(map_left), map_right: Some(map_right) }
+    }
+
+    /// Returns a future calling `inspect` with a reference to the left output of this future,
+    /// before returning it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either_fair, Either};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let mut seen = None;
+    /// let out = either_fair(async { 42 }, future::pending::<bool>())
+    ///     .inspect_left(|left| seen = Some(*left))
+    ///     .await;
+    /// assert_eq!(out, Either::Left(42));
+    /// assert_eq!(seen, Some(42));
+    /// #
+    /// # });
+    /// ```
+    pub fn inspect_left<I>(self, inspect: I) -> futs::InspectLeft<Self, I>
+    where
+        L: Future,
+        R: Future,
+        I: FnOnce(&L::Output),
+    {
+        futs::InspectLeft { fut: self, inspect: Some(inspect) }
+    }
+
+    /// Returns a future calling `inspect` with a reference to the right output of this future,
+    /// before returning it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either_fair, Either};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let mut seen = None;
+    /// let out = either_fair(future::pending::<bool>(), async { 42 })
+    ///     .inspect_right(|right| seen = Some(*right))
+    ///     .await;
+    /// assert_eq!(out, Either::Right(42));
+    /// assert_eq!(seen, Some(42));
+    /// #
+    /// # });
+    /// ```
+    pub fn inspect_right<I>(self, inspect: I) -> futs::InspectRight<Self, I>
+    where
+        L: Future,
+        R: Future,
+        I: FnOnce(&R::Output),
+    {
+        futs::InspectRight { fut: self, inspect: Some(inspect) }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    /// Returns this future, boxed and with its type erased.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either_fair, BoxEither, Either};
+    ///
+    /// fn race() -> BoxEither<'static, i32, bool> {
+    ///     either_fair(async { 42 }, future::pending()).boxed()
+    /// }
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// assert_eq!(race().await, Either::Left(42));
+    /// #
+    /// # });
+    /// ```
+    pub fn boxed<'a>(self) -> BoxEither<'a, L::Output, R::Output>
+    where
+        L: Future + Send + 'a,
+        R: Future + Send + 'a,
+    {
+        Box::pin(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    /// Returns this future, boxed and with its type erased, without requiring it to be [`Send`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use futures_lite::future;
+    /// use futures_either::{either_fair, Either, LocalBoxEither};
+    ///
+    /// fn race() -> LocalBoxEither<'static, Rc<i32>, bool> {
+    ///     either_fair(async { Rc::new(42) }, future::pending()).boxed_local()
+    /// }
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// assert_eq!(race().await, Either::Left(Rc::new(42)));
+    /// #
+    /// # });
+    /// ```
+    pub fn boxed_local<'a>(self) -> LocalBoxEither<'a, L::Output, R::Output>
+    where
+        L: Future + 'a,
+        R: Future + 'a,
+    {
+        Box::pin(self)
+    }
+}
+
+impl<L, R, C> BitOr<C> for futs::Either<L, R> {
+    type Output = futs::Either3<L, R, C>;
+
+    fn bitor(self, third: C) -> Self::Output {
+        self.or(third)
+    }
+}
+
+impl<A, B, C> futs::Either<futs::Either<A, B>, C> {
+    /// Returns a future polling the three futures in the same order as this one, but returning
+    /// the output of the first one to complete as an [`Either3`] instead of a nested [`Either`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::{either, Either3};
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either(
+    ///     either(future::pending::<i32>(), async { 42 }),
+    ///     async { false },
+    /// ).flatten().await;
+    /// assert_eq!(out, Either3::Second(42));
+    /// #
+    /// # });
+    /// ```
+    pub fn flatten(self) -> futs::Either3<A, B, C> {
+        self.into()
+    }
+}
+
+impl<A, B, C> From<futs::Either<futs::Either<A, B>, C>> for futs::Either3<A, B, C> {
+    fn from(fut: futs::Either<futs::Either<A, B>, C>) -> Self {
+        futs::Either3 { first: fut.left.left, second: fut.left.right, third: fut.right }
+    }
+}
+
+impl<A, B, C> From<futs::Either<A, futs::Either<B, C>>> for futs::Either3<A, B, C> {
+    fn from(fut: futs::Either<A, futs::Either<B, C>>) -> Self {
+        futs::Either3 { first: fut.left, second: fut.right.left, third: fut.right.right }
+    }
+}
+
+impl<A, B, C> From<Either<Either<A, B>, C>> for Either3<A, B, C> {
+    fn from(out: Either<Either<A, B>, C>) -> Self {
+        match out {
+            Either::Left(Either::Left(out)) => Either3::First(out),
+            Either::Left(Either::Right(out)) => Either3::Second(out),
+            Either::Right(out) => Either3::Third(out),
+        }
+    }
+}
+
+impl<A, B, C> From<Either<A, Either<B, C>>> for Either3<A, B, C> {
+    fn from(out: Either<A, Either<B, C>>) -> Self {
+        match out {
+            Either::Left(out) => Either3::First(out),
+            Either::Right(Either::Left(out)) => Either3::Second(out),
+            Either::Right(Either::Right(out)) => Either3::Third(out),
+        }
+    }
+}
+
+either_n!(@or Either3 { first: A, second: B, third: C } => Either4 { fourth: D });
+either_n!(@or Either4 { first: A, second: B, third: C, fourth: D } => Either5 { fifth: E });
+either_n!(@or
+    Either5 { first: A, second: B, third: C, fourth: D, fifth: E }
+    => Either6 { sixth: F }
+);
+either_n!(@or
+    Either6 { first: A, second: B, third: C, fourth: D, fifth: E, sixth: F }
+    => Either7 { seventh: G }
+);
+either_n!(@or
+    Either7 { first: A, second: B, third: C, fourth: D, fifth: E, sixth: F, seventh: G }
+    => Either8 { eighth: H }
+);
+either_n!(@or
+    Either8 { first: A, second: B, third: C, fourth: D, fifth: E, sixth: F, seventh: G, eighth: H }
+    => Either9 { ninth: I }
+);
+either_n!(@or
+    Either9 {
+        first: A, second: B, third: C, fourth: D, fifth: E, sixth: F, seventh: G, eighth: H,
+        ninth: I
+    } => Either10 { tenth: J }
+);
+either_n!(@or
+    Either10 {
+        first: A, second: B, third: C, fourth: D, fifth: E, sixth: F, seventh: G, eighth: H,
+        ninth: I, tenth: J
+    } => Either11 { eleventh: K }
+);
+either_n!(@or
+    Either11 {
+        first: A, second: B, third: C, fourth: D, fifth: E, sixth: F, seventh: G, eighth: H,
+        ninth: I, tenth: J, eleventh: K
+    } => Either12 { twelfth: L }
+);
+
+#[cfg(feature = "fair-lite")]
+impl<L, R> futs::EitherFair<L, R> {
+    /// Returns a future returning the output of the first future to complete, converted into `T`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::either_fair;
+    ///
+    /// # future::block_on(async {
+    /// #
+    /// let out = either_fair(
+    ///     future::pending::<i32>(),
+    ///     async { 24u8 },
+    /// ).unify::<i64>().await;
+    /// assert_eq!(out, 24);
+    /// #
+    /// # });
+    /// ```
+    pub fn unify<T>(self) -> futs::Unify<Self, T>
+    where
+        L: Future,
+        R: Future,
+        L::Output: Into<T>,
+        R::Output: Into<T>,
+    {
+        futs::Unify { fut: self, _out: PhantomData }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F, T> Clone for futs::Cache<F, T> {
+    fn clone(&self) -> Self {
+        futs::Cache { state: self.state.clone() }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F, T> Clone for futs::CacheLocal<F, T> {
+    fn clone(&self) -> Self {
+        futs::CacheLocal { state: self.state.clone() }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F, T> Drop for futs::Cache<F, T> {
+    fn drop(&mut self) {
+        let wakers = mem::take(&mut lock(&self.state).wakers);
+        wakers.into_iter().for_each(Waker::wake);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F, T> Drop for futs::CacheLocal<F, T> {
+    fn drop(&mut self) {
+        let wakers = mem::take(&mut RefCell::borrow_mut(&self.state).wakers);
+        wakers.into_iter().for_each(Waker::wake);
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<L, R> Drop for futs::EitherTasks<L, R> {
+    fn drop(&mut self) {
+        self.left.abort();
+        self.right.abort();
+    }
+}
+
+// ============================================ Race ============================================ \\
+
+/// A trait implemented for tuples of two to twelve futures, allowing to race them.
+///
+/// Calling [`race()`] on a tuple is the same as calling [`either()`] or the [`either3()`] to
+/// [`either12()`] function matching its size.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{Either, Either3, Race};
+///
+/// # future::block_on(async {
+/// #
+/// let out = (async { 42 }, async { false }).race().await;
+/// assert_eq!(out, Either::Left(42));
+///
+/// let out = (
+///     future::pending::<bool>(),
+///     async { 42 },
+///     async { false },
+/// ).race().await;
+/// assert_eq!(out, Either3::Second(42));
+/// #
+/// # });
+/// ```
+///
+/// [`race()`]: Race::race()
+pub trait Race {
+    /// The output of the first future to complete.
+    type Output;
+
+    /// The [`Future`] returned by [`race()`].
+    ///
+    /// [`race()`]: Race::race()
+    type Future: Future<Output = Self::Output>;
+
+    /// Returns a future polling the futures and returning the output of the first one to complete.
+    fn race(self) -> Self::Future;
+}
+
+impl<L, R> Race for (L, R)
+where
+    L: Future,
+    R: Future,
+{
+    type Output = Either<L::Output, R::Output>;
+    type Future = futs::Either<L, R>;
+
+    fn race(self) -> Self::Future {
+        either(self.0, self.1)
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+/// A trait implemented for pairs of futures, allowing to race them "fairly".
+///
+/// Calling [`race_fair()`] on a pair is the same as calling [`either_fair()`].
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{Either, RaceFair};
+///
+/// # future::block_on(async {
+/// #
+/// let out = (future::pending::<bool>(), async { 42 }).race_fair().await;
+/// assert_eq!(out, Either::Right(42));
+/// #
+/// # });
+/// ```
+///
+/// [`race_fair()`]: RaceFair::race_fair()
+pub trait RaceFair {
+    /// The output of the first future to complete.
+    type Output;
+
+    /// The [`Future`] returned by [`race_fair()`].
+    ///
+    /// [`race_fair()`]: RaceFair::race_fair()
+    type Future: Future<Output = Self::Output>;
+
+    /// Returns a future polling the futures and returning the output of the first one to
+    /// complete, choosing which future to poll first randomly, each time it is being polled.
+    fn race_fair(self) -> Self::Future;
+}
+
+#[cfg(feature = "fair-lite")]
+impl<L, R> RaceFair for (L, R)
+where
+    L: Future,
+    R: Future,
+{
+    type Output = Either<L::Output, R::Output>;
+    type Future = futs::EitherFair<L, R>;
+
+    fn race_fair(self) -> Self::Future {
+        either_fair(self.0, self.1)
+    }
+}
+
+/// A trait implemented for tuples of two to twelve fallible futures, allowing to race them.
+///
+/// Calling [`try_race()`] on a tuple is the same as calling [`try_either()`] or the
+/// [`try_either3()`] to [`try_either12()`] function matching its size.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{Either3, TryRace};
+///
+/// # future::block_on(async {
+/// #
+/// let out = (
+///     future::pending::<Result<bool, ()>>(),
+///     async { Ok::<_, ()>(42) },
+///     async { Err::<bool, _>(()) },
+/// ).try_race().await;
+/// assert_eq!(out, Ok(Either3::Second(42)));
+/// #
+/// # });
+/// ```
+///
+/// [`try_race()`]: TryRace::try_race()
+pub trait TryRace {
+    /// The output of the first future to succeed, if it completes first.
+    type Ok;
+
+    /// The error returned by the first future to fail, if it completes first.
+    type Error;
+
+    /// The [`Future`] returned by [`try_race()`].
+    ///
+    /// [`try_race()`]: TryRace::try_race()
+    type Future: Future<Output = Result<Self::Ok, Self::Error>>;
+
+    /// Returns a future polling the futures and returning a result with the output or error
+    /// returned by the first one to complete.
+    fn try_race(self) -> Self::Future;
+}
+
+impl<OL, OR, E, L, R> TryRace for (L, R)
+where
+    L: Future<Output = Result<OL, E>>,
+    R: Future<Output = Result<OR, E>>,
+{
+    type Ok = Either<OL, OR>;
+    type Error = E;
+    type Future = futs::TryEither<L, R>;
+
+    fn try_race(self) -> Self::Future {
+        try_either(self.0, self.1)
+    }
+}
+
+// ========================================== EitherExt ========================================= \\
+
+/// An extension trait for [`Either`]s of futures, allowing to await them without matching on them
+/// first.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_lite::future;
+/// use futures_either::{Either, EitherExt};
+///
+/// # future::block_on(async {
+/// #
+/// let fut = Either::<_, future::Ready<bool>>::Left(async { 42 });
+/// assert_eq!(fut.future().await, Either::Left(42));
+///
+/// let fut = Either::<future::Ready<i32>, _>::Right(async { 42 });
+/// assert_eq!(fut.unified().await, 42);
+/// #
+/// # });
+/// ```
+pub trait EitherExt<L, R> {
+    /// Returns a future polling the contained future and returning its output, wrapped in the
+    /// same side it was on.
+    fn future(self) -> futs::EitherFuture<L::IntoFuture, R::IntoFuture>
+    where
+        L: IntoFuture,
+        R: IntoFuture;
+
+    /// Returns a future polling the contained future and returning its output, which must have
+    /// the same type on both sides, as [`left()`] and [`right()`].
+    fn unified(self) -> futs::Branch<L::IntoFuture, R::IntoFuture>
+    where
+        L: IntoFuture,
+        R: IntoFuture<Output = L::Output>;
+}
+
+impl<L, R> EitherExt<L, R> for Either<L, R> {
+    fn future(self) -> futs::EitherFuture<L::IntoFuture, R::IntoFuture>
+    where
+        L: IntoFuture,
+        R: IntoFuture,
+    {
+        futs::EitherFuture { fut: self.map_either(L::into_future, R::into_future) }
+    }
+
+    fn unified(self) -> futs::Branch<L::IntoFuture, R::IntoFuture>
+    where
+        L: IntoFuture,
+        R: IntoFuture<Output = L::Output>,
+    {
+        futs::Branch { fut: self.map_either(L::into_future, R::into_future) }
+    }
+}
+
+// ========================================= impl Debug ========================================= \\
+
+impl<L, R> fmt::Debug for futs::Either<L, R> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("Either")
+            .field("left", &type_name::<L>())
+            .field("right", &type_name::<R>())
+            .finish()
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<L, R> fmt::Debug for futs::EitherFair<L, R> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("EitherFair")
+            .field("left", &type_name::<L>())
+            .field("right", &type_name::<R>())
+            .finish()
+    }
+}
+
+impl<L, R> fmt::Debug for futs::TryEither<L, R> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("TryEither")
+            .field("left", &type_name::<L>())
+            .field("right", &type_name::<R>())
+            .finish()
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<L, R> fmt::Debug for futs::TryEitherFair<L, R> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("TryEitherFair")
+            .field("left", &type_name::<L>())
+            .field("right", &type_name::<R>())
+            .finish()
+    }
+}
+
+// ========================================= impl Future ======================================== \\
+
+impl<L, R> Future for futs::Either<L, R>
+where
+    L: Future,
+    R: Future,
+{
+    type Output = Either<L::Output, R::Output>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let (left, right) = (this.left, this.right);
+        let out = this.spins.poll(ctx, type_name::<Self>, |ctx| {
+            poll_in_order(ctx, Side::Left, left, right)
+        });
+        *this.done = out.is_ready();
+        out
+    }
+}
+
+fn poll_in_order<L, R>(
+    ctx: &mut Context,
+    first: Side,
+    left: Pin<&mut L>,
+    right: Pin<&mut R>,
+) -> Poll<Either<L::Output, R::Output>>
+where
+    L: Future,
+    R: Future,
+{
+    let mut left = Some(left);
+    let mut right = Some(right);
+
+    for side in [first, first.other()] {
+        match side {
+            Side::Left => {
+                let fut = left.take().expect("`left` should have been set");
+                if let Poll::Ready(out) = fut.poll(ctx) {
+                    return Poll::Ready(Either::Left(out));
+                }
+            }
+            Side::Right => {
+                let fut = right.take().expect("`right` should have been set");
+                if let Poll::Ready(out) = fut.poll(ctx) {
+                    return Poll::Ready(Either::Right(out));
+                }
+            }
+        }
+    }
+
+    Poll::Pending
+}
+
+fn poll_try_in_order<OL, OR, E, L, R>(
+    ctx: &mut Context,
+    first: Side,
+    left: Pin<&mut L>,
+    right: Pin<&mut R>,
+) -> Poll<Result<Either<OL, OR>, E>>
+where
+    L: Future<Output = Result<OL, E>>,
+    R: Future<Output = Result<OR, E>>,
+{
+    let mut left = Some(left);
+    let mut right = Some(right);
+
+    for side in [first, first.other()] {
+        match side {
+            Side::Left => {
+                let fut = left.take().expect("`left` should have been set");
+                if let Poll::Ready(out) = fut.poll(ctx) {
+                    return Poll::Ready(out.map(Either::Left));
+                }
+            }
+            Side::Right => {
+                let fut = right.take().expect("`right` should have been set");
+                if let Poll::Ready(out) = fut.poll(ctx) {
+                    return Poll::Ready(out.map(Either::Right));
+                }
+            }
+        }
+    }
+
+    Poll::Pending
+}
+
+#[cfg(feature = "fair-lite")]
+impl<L, R> Future for futs::EitherFair<L, R>
+where
+    L: Future,
+    R: Future,
+{
+    type Output = Either<L::Output, R::Output>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let (left, right, state) = (this.left, this.right, this.state);
+        let out = this.spins.poll(ctx, type_name::<Self>, |ctx| {
+            poll_in_order(ctx, fair_first(state), left, right)
+        });
+        *this.done = out.is_ready();
+        out
+    }
+}
+
+/// Returns which future to poll first, using (and seeding, if it hasn't been yet or if it got
+/// seeded with another seed epoch) the generator `state`, unless [`Random`] has to be asked every
+/// time.
+#[cfg(feature = "fair-lite")]
+fn fair_first(state: &mut FairState) -> Side {
+    if !Random::cacheable() {
+        return Random.first();
+    }
+
+    let epoch = Random::epoch();
+    if state.epoch != epoch {
+        *state = FairState { rng: Random::seed_state(), epoch };
+    }
+
+    // xorshift64
+    let rng = &mut state.rng;
+    *rng ^= *rng << 13;
+    *rng ^= *rng >> 7;
+    *rng ^= *rng << 17;
+
+    if *rng >> 63 == 1 {
+        Side::Left
+    } else {
+        Side::Right
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl FairState {
+    const fn new() -> Self {
+        FairState { rng: 0, epoch: 0 }
+    }
+}
+
+impl<OL, OR, E, L, R> Future for futs::TryEither<L, R>
+where
+    L: Future<Output = Result<OL, E>>,
+    R: Future<Output = Result<OR, E>>,
+{
+    type Output = Result<Either<OL, OR>, E>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let (left, right) = (this.left, this.right);
+        let out = this.spins.poll(ctx, type_name::<Self>, |ctx| {
+            poll_try_in_order(ctx, Side::Left, left, right)
+        });
+        *this.done = out.is_ready();
+        out
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<OL, OR, E, L, R> Future for futs::TryEitherFair<L, R>
+where
+    L: Future<Output = Result<OL, E>>,
+    R: Future<Output = Result<OR, E>>,
+{
+    type Output = Result<Either<OL, OR>, E>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let (left, right, state) = (this.left, this.right, this.state);
+        let out = this.spins.poll(ctx, type_name::<Self>, |ctx| {
+            poll_try_in_order(ctx, fair_first(state), left, right)
+        });
+        *this.done = out.is_ready();
+        out
+    }
+}
+
+impl<F, const N: usize> Future for futs::RaceArray<F, N>
+where
+    F: Future + Unpin,
+{
+    type Output = (usize, F::Output);
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        for (idx, fut) in self.get_mut().futs.iter_mut().enumerate() {
+            if let Poll::Ready(out) = Pin::new(fut).poll(ctx) {
+                return Poll::Ready((idx, out));
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F> Future for futs::RaceIter<F>
+where
+    F: Future,
+{
+    type Output = (usize, F::Output);
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let mut ready = None;
+        for (idx, fut) in this.futs.iter_mut().enumerate() {
+            if let Poll::Ready(out) = fut.as_mut().poll(ctx) {
+                ready = Some((idx, out));
+                break;
+            }
+        }
+
+        if let Some(out) = ready {
+            *this.futs = Box::default();
+            Poll::Ready(out)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, E, F> Future for futs::SelectOk<F, E>
+where
+    F: Future<Output = Result<T, E>>,
+{
+    type Output = Result<(usize, T), Vec<E>>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let mut ready = None;
+        for (idx, slot) in this.futs.iter_mut().enumerate() {
+            let fut = if let Some(fut) = slot {
+                fut
+            } else {
+                continue;
+            };
+
+            match fut.as_mut().poll(ctx) {
+                Poll::Ready(Ok(out)) => {
+                    ready = Some((idx, out));
+                    break;
+                }
+                Poll::Ready(Err(err)) => {
+                    *slot = None;
+                    this.errs[idx] = Some(err);
+                }
+                Poll::Pending => (),
+            }
+        }
+
+        if let Some(out) = ready {
+            *this.futs = Box::default();
+            Poll::Ready(Ok(out))
+        } else if this.errs.iter().all(Option::is_some) {
+            Poll::Ready(Err(this.errs.drain(..).flatten().collect()))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F> Future for futs::Quorum<F, F::Output>
+where
+    F: Future,
+{
+    type Output = Vec<(usize, F::Output)>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        for (idx, slot) in this.futs.iter_mut().enumerate() {
+            if this.outs.len() >= *this.k {
+                break;
+            }
+
+            if let Some(fut) = slot {
+                if let Poll::Ready(out) = fut.as_mut().poll(ctx) {
+                    *slot = None;
+                    this.outs.push((idx, out));
+                }
+            }
+        }
+
+        if this.outs.len() >= *this.k {
+            *this.futs = Box::default();
+            Poll::Ready(core::mem::take(this.outs))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F> Future for futs::AllSettled<F, F::Output>
+where
+    F: Future,
+{
+    type Output = Vec<F::Output>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        for (idx, slot) in this.futs.iter_mut().enumerate() {
+            if let Some(fut) = slot {
+                if let Poll::Ready(out) = fut.as_mut().poll(ctx) {
+                    *slot = None;
+                    this.outs[idx] = Some(out);
+                }
+            }
+        }
+
+        if this.outs.iter().all(Option::is_some) {
+            Poll::Ready(this.outs.drain(..).flatten().collect())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<L, R> futs::Race<L, R> {
+    /// Returns the same future as [`race()`], but taking futures directly, which allows to use it
+    /// in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::Race { fut: futs::Either::new(left, right) }
+    }
+}
+
+impl<T, L, R> Future for futs::Race<L, R>
+where
+    L: Future<Output = T>,
+    R: Future<Output = T>,
+{
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            Poll::Ready(out.into_inner())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<L, R> futs::RaceFair<L, R> {
+    /// Returns the same future as [`race_fair()`], but taking futures directly, which allows to use
+    /// it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::RaceFair { fut: futs::EitherFair::new(left, right) }
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<T, L, R> Future for futs::RaceFair<L, R>
+where
+    L: Future<Output = T>,
+    R: Future<Output = T>,
+{
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            Poll::Ready(out.into_inner())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<L, R> futs::TryRace<L, R> {
+    /// Returns the same future as [`try_race()`], but taking futures directly, which allows to use
+    /// it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::TryRace { fut: futs::Either::new(left, right) }
+    }
+}
+
+impl<T, E, L, R> Future for futs::TryRace<L, R>
+where
+    L: Future<Output = Result<T, E>>,
+    R: Future<Output = Result<T, E>>,
+{
+    type Output = Result<T, E>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            Poll::Ready(out.into_inner())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<L, R> futs::TryRaceFair<L, R> {
+    /// Returns the same future as [`try_race_fair()`], but taking futures directly, which allows to
+    /// use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::TryRaceFair { fut: futs::EitherFair::new(left, right) }
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<T, E, L, R> Future for futs::TryRaceFair<L, R>
+where
+    L: Future<Output = Result<T, E>>,
+    R: Future<Output = Result<T, E>>,
+{
+    type Output = Result<T, E>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            Poll::Ready(out.into_inner())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<L, R, EL, ER> futs::RaceOk<L, R, EL, ER> {
+    /// Returns the same future as [`race_ok()`], but taking futures directly, which allows to use
+    /// it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::RaceOk { left, right, left_err: None, right_err: None }
+    }
+}
+
+impl<OL, OR, EL, ER, L, R> Future for futs::RaceOk<L, R, EL, ER>
+where
+    L: Future<Output = Result<OL, EL>>,
+    R: Future<Output = Result<OR, ER>>,
+{
+    type Output = Result<Either<OL, OR>, AggregateError<EL, ER>>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        poll_race_ok(ctx, this.left, this.right, this.left_err, this.right_err, true)
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<L, R, EL, ER> futs::RaceOkFair<L, R, EL, ER> {
+    /// Returns the same future as [`race_ok_fair()`], but taking futures directly, which allows to
+    /// use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::RaceOkFair { left, right, left_err: None, right_err: None }
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<OL, OR, EL, ER, L, R> Future for futs::RaceOkFair<L, R, EL, ER>
+where
+    L: Future<Output = Result<OL, EL>>,
+    R: Future<Output = Result<OR, ER>>,
+{
+    type Output = Result<Either<OL, OR>, AggregateError<EL, ER>>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let left_first = Random.first() == Side::Left;
+        poll_race_ok(ctx, this.left, this.right, this.left_err, this.right_err, left_first)
+    }
+}
+
+fn poll_race_ok<OL, OR, EL, ER, L, R>(
+    ctx: &mut Context,
+    left: Pin<&mut L>,
+    right: Pin<&mut R>,
+    left_err: &mut Option<EL>,
+    right_err: &mut Option<ER>,
+    left_first: bool,
+) -> Poll<Result<Either<OL, OR>, AggregateError<EL, ER>>>
+where
+    L: Future<Output = Result<OL, EL>>,
+    R: Future<Output = Result<OR, ER>>,
+{
+    let mut left = left_err.is_none().then_some(left);
+    let mut right = right_err.is_none().then_some(right);
+
+    for poll_left in [left_first, !left_first] {
+        if poll_left {
+            if let Some(fut) = left.take() {
+                match fut.poll(ctx) {
+                    Poll::Ready(Ok(out)) => return Poll::Ready(Ok(Either::Left(out))),
+                    Poll::Ready(Err(err)) => *left_err = Some(err),
+                    Poll::Pending => (),
+                }
+            }
+        } else if let Some(fut) = right.take() {
+            match fut.poll(ctx) {
+                Poll::Ready(Ok(out)) => return Poll::Ready(Ok(Either::Right(out))),
+                Poll::Ready(Err(err)) => *right_err = Some(err),
+                Poll::Pending => (),
+            }
+        }
+    }
+
+    if left_err.is_some() && right_err.is_some() {
+        let left = left_err.take().unwrap();
+        let right = right_err.take().unwrap();
+
+        Poll::Ready(Err(AggregateError { left, right }))
+    } else {
+        Poll::Pending
+    }
+}
+
+impl<L, R> futs::TryEitherSided<L, R> {
+    /// Returns the same future as [`try_either_sided()`], but taking futures directly, which allows
+    /// to use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::TryEitherSided { fut: futs::Either::new(left, right) }
+    }
+}
+
+impl<OL, OR, E, L, R> Future for futs::TryEitherSided<L, R>
+where
+    L: Future<Output = Result<OL, E>>,
+    R: Future<Output = Result<OR, E>>,
+{
+    type Output = Result<Either<OL, OR>, Either<E, E>>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            match out {
+                Either::Left(Ok(left)) => Ok(Either::Left(left)),
+                Either::Right(Ok(right)) => Ok(Either::Right(right)),
+                Either::Left(Err(err)) => Err(Either::Left(err)),
+                Either::Right(Err(err)) => Err(Either::Right(err)),
+            }.into()
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<L, R> futs::TryEitherSidedFair<L, R> {
+    /// Returns the same future as [`try_either_sided_fair()`], but taking futures directly, which
+    /// allows to use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::TryEitherSidedFair { fut: futs::EitherFair::new(left, right) }
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<OL, OR, E, L, R> Future for futs::TryEitherSidedFair<L, R>
+where
+    L: Future<Output = Result<OL, E>>,
+    R: Future<Output = Result<OR, E>>,
+{
+    type Output = Result<Either<OL, OR>, Either<E, E>>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            match out {
+                Either::Left(Ok(left)) => Ok(Either::Left(left)),
+                Either::Right(Ok(right)) => Ok(Either::Right(right)),
+                Either::Left(Err(err)) => Err(Either::Left(err)),
+                Either::Right(Err(err)) => Err(Either::Right(err)),
+            }.into()
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<L, R, E> futs::TryEitherErrInto<L, R, E> {
+    /// Returns the same future as [`try_either_err_into()`], but taking futures directly, which
+    /// allows to use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::TryEitherErrInto { fut: futs::Either::new(left, right), _err: PhantomData }
+    }
+}
+
+impl<OL, OR, EL, ER, E, L, R> Future for futs::TryEitherErrInto<L, R, E>
+where
+    L: Future<Output = Result<OL, EL>>,
+    R: Future<Output = Result<OR, ER>>,
+    EL: Into<E>,
+    ER: Into<E>,
+{
+    type Output = Result<Either<OL, OR>, E>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            match out {
+                Either::Left(Ok(left)) => Ok(Either::Left(left)),
+                Either::Right(Ok(right)) => Ok(Either::Right(right)),
+                Either::Left(Err(err)) => Err(err.into()),
+                Either::Right(Err(err)) => Err(err.into()),
+            }.into()
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<L, R, E> futs::TryEitherErrIntoFair<L, R, E> {
+    /// Returns the same future as [`try_either_err_into_fair()`], but taking futures directly,
+    /// which allows to use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::TryEitherErrIntoFair { fut: futs::EitherFair::new(left, right), _err: PhantomData }
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<OL, OR, EL, ER, E, L, R> Future for futs::TryEitherErrIntoFair<L, R, E>
+where
+    L: Future<Output = Result<OL, EL>>,
+    R: Future<Output = Result<OR, ER>>,
+    EL: Into<E>,
+    ER: Into<E>,
+{
+    type Output = Result<Either<OL, OR>, E>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            match out {
+                Either::Left(Ok(left)) => Ok(Either::Left(left)),
+                Either::Right(Ok(right)) => Ok(Either::Right(right)),
+                Either::Left(Err(err)) => Err(err.into()),
+                Either::Right(Err(err)) => Err(err.into()),
+            }.into()
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<L, R> futs::TryEitherErrs<L, R> {
+    /// Returns the same future as [`try_either_errs()`], but taking futures directly, which allows
+    /// to use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::TryEitherErrs { fut: futs::Either::new(left, right) }
+    }
+}
+
+impl<OL, OR, EL, ER, L, R> Future for futs::TryEitherErrs<L, R>
+where
+    L: Future<Output = Result<OL, EL>>,
+    R: Future<Output = Result<OR, ER>>,
+{
+    type Output = Result<Either<OL, OR>, Either<EL, ER>>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            match out {
+                Either::Left(Ok(left)) => Ok(Either::Left(left)),
+                Either::Right(Ok(right)) => Ok(Either::Right(right)),
+                Either::Left(Err(err)) => Err(Either::Left(err)),
+                Either::Right(Err(err)) => Err(Either::Right(err)),
+            }.into()
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<L, R> futs::TryEitherErrsFair<L, R> {
+    /// Returns the same future as [`try_either_errs_fair()`], but taking futures directly, which
+    /// allows to use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::TryEitherErrsFair { fut: futs::EitherFair::new(left, right) }
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<OL, OR, EL, ER, L, R> Future for futs::TryEitherErrsFair<L, R>
+where
+    L: Future<Output = Result<OL, EL>>,
+    R: Future<Output = Result<OR, ER>>,
+{
+    type Output = Result<Either<OL, OR>, Either<EL, ER>>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            match out {
+                Either::Left(Ok(left)) => Ok(Either::Left(left)),
+                Either::Right(Ok(right)) => Ok(Either::Right(right)),
+                Either::Left(Err(err)) => Err(Either::Left(err)),
+                Either::Right(Err(err)) => Err(Either::Right(err)),
+            }.into()
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<L, R> futs::EitherControlFlow<L, R> {
+    /// Returns the same future as [`either_control_flow()`], but taking futures directly, which
+    /// allows to use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::EitherControlFlow { fut: futs::Either::new(left, right) }
+    }
+}
+
+impl<B, CL, CR, L, R> Future for futs::EitherControlFlow<L, R>
+where
+    L: Future<Output = ControlFlow<B, CL>>,
+    R: Future<Output = ControlFlow<B, CR>>,
+{
+    type Output = ControlFlow<B, Either<CL, CR>>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            match out {
+                Either::Left(ControlFlow::Continue(left)) => {
+                    ControlFlow::Continue(Either::Left(left))
+                }
+                Either::Right(ControlFlow::Continue(right)) => {
+                    ControlFlow::Continue(Either::Right(right))
+                }
+                Either::Left(ControlFlow::Break(brk)) | Either::Right(ControlFlow::Break(brk)) => {
+                    ControlFlow::Break(brk)
+                }
+            }.into()
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<L, R> futs::EitherControlFlowFair<L, R> {
+    /// Returns the same future as [`either_control_flow_fair()`], but taking futures directly,
+    /// which allows to use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::EitherControlFlowFair { fut: futs::EitherFair::new(left, right) }
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<B, CL, CR, L, R> Future for futs::EitherControlFlowFair<L, R>
+where
+    L: Future<Output = ControlFlow<B, CL>>,
+    R: Future<Output = ControlFlow<B, CR>>,
+{
+    type Output = ControlFlow<B, Either<CL, CR>>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            match out {
+                Either::Left(ControlFlow::Continue(left)) => {
+                    ControlFlow::Continue(Either::Left(left))
+                }
+                Either::Right(ControlFlow::Continue(right)) => {
+                    ControlFlow::Continue(Either::Right(right))
+                }
+                Either::Left(ControlFlow::Break(brk)) | Either::Right(ControlFlow::Break(brk)) => {
+                    ControlFlow::Break(brk)
+                }
+            }.into()
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<L, R> futs::EitherSome<L, R> {
+    /// Returns the same future as [`either_some()`], but taking futures directly, which allows to
+    /// use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::EitherSome { left, right, left_none: false, right_none: false }
+    }
+}
+
+impl<OL, OR, L, R> Future for futs::EitherSome<L, R>
+where
+    L: Future<Output = Option<OL>>,
+    R: Future<Output = Option<OR>>,
+{
+    type Output = Option<Either<OL, OR>>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        poll_either_some(ctx, this.left, this.right, this.left_none, this.right_none, true)
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<L, R> futs::EitherSomeFair<L, R> {
+    /// Returns the same future as [`either_some_fair()`], but taking futures directly, which allows
+    /// to use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::EitherSomeFair { left, right, left_none: false, right_none: false }
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<OL, OR, L, R> Future for futs::EitherSomeFair<L, R>
+where
+    L: Future<Output = Option<OL>>,
+    R: Future<Output = Option<OR>>,
+{
+    type Output = Option<Either<OL, OR>>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let left_first = Random.first() == Side::Left;
+
+        poll_either_some(ctx, this.left, this.right, this.left_none, this.right_none, left_first)
+    }
+}
+
+fn poll_either_some<OL, OR, L, R>(
+    ctx: &mut Context,
+    left: Pin<&mut L>,
+    right: Pin<&mut R>,
+    left_none: &mut bool,
+    right_none: &mut bool,
+    left_first: bool,
+) -> Poll<Option<Either<OL, OR>>>
+where
+    L: Future<Output = Option<OL>>,
+    R: Future<Output = Option<OR>>,
+{
+    let mut left = (!*left_none).then_some(left);
+    let mut right = (!*right_none).then_some(right);
+
+    for poll_left in [left_first, !left_first] {
+        if poll_left {
+            if let Some(fut) = left.take() {
+                match fut.poll(ctx) {
+                    Poll::Ready(Some(out)) => return Poll::Ready(Some(Either::Left(out))),
+                    Poll::Ready(None) => *left_none = true,
+                    Poll::Pending => (),
+                }
+            }
+        } else if let Some(fut) = right.take() {
+            match fut.poll(ctx) {
+                Poll::Ready(Some(out)) => return Poll::Ready(Some(Either::Right(out))),
+                Poll::Ready(None) => *right_none = true,
+                Poll::Pending => (),
+            }
+        }
+    }
+
+    if *left_none && *right_none {
+        Poll::Ready(None)
+    } else {
+        Poll::Pending
+    }
+}
+
+impl<L, R> futs::TryEitherOpt<L, R> {
+    /// Returns the same future as [`try_either_opt()`], but taking futures directly, which allows
+    /// to use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::TryEitherOpt { fut: futs::Either::new(left, right) }
+    }
+}
+
+impl<OL, OR, L, R> Future for futs::TryEitherOpt<L, R>
+where
+    L: Future<Output = Option<OL>>,
+    R: Future<Output = Option<OR>>,
+{
+    type Output = Option<Either<OL, OR>>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            match out {
+                Either::Left(left) => left.map(Either::Left),
+                Either::Right(right) => right.map(Either::Right),
+            }.into()
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<L, R> futs::TryEitherOptFair<L, R> {
+    /// Returns the same future as [`try_either_opt_fair()`], but taking futures directly, which
+    /// allows to use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::TryEitherOptFair { fut: futs::EitherFair::new(left, right) }
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl<OL, OR, L, R> Future for futs::TryEitherOptFair<L, R>
+where
+    L: Future<Output = Option<OL>>,
+    R: Future<Output = Option<OR>>,
+{
+    type Output = Option<Either<OL, OR>>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            match out {
+                Either::Left(left) => left.map(Either::Left),
+                Either::Right(right) => right.map(Either::Right),
+            }.into()
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<L, R, OL, OR> futs::TryBoth<L, R, OL, OR> {
+    /// Returns the same future as [`try_both()`], but taking futures directly, which allows to use
+    /// it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::TryBoth { left, right, left_out: None, right_out: None }
+    }
+}
+
+impl<OL, OR, E, L, R> Future for futs::TryBoth<L, R, OL, OR>
+where
+    L: Future<Output = Result<OL, E>>,
+    R: Future<Output = Result<OR, E>>,
+{
+    type Output = Result<(OL, OR), E>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if this.left_out.is_none() {
+            match this.left.poll(ctx) {
+                Poll::Ready(Ok(out)) => *this.left_out = Some(out),
+                Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                Poll::Pending => (),
+            }
+        }
+
+        if this.right_out.is_none() {
+            match this.right.poll(ctx) {
+                Poll::Ready(Ok(out)) => *this.right_out = Some(out),
+                Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                Poll::Pending => (),
+            }
+        }
+
+        if this.left_out.is_some() && this.right_out.is_some() {
+            Poll::Ready(Ok((this.left_out.take().unwrap(), this.right_out.take().unwrap())))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<L, R, OL, OR> futs::Both<L, R, OL, OR> {
+    /// Returns the same future as [`both()`], but taking futures directly, which allows to use it
+    /// in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::Both { left, right, left_out: None, right_out: None }
+    }
+}
+
+impl<L, R> Future for futs::Both<L, R, L::Output, R::Output>
+where
+    L: Future,
+    R: Future,
+{
+    type Output = (L::Output, R::Output);
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if this.left_out.is_none() {
+            if let Poll::Ready(out) = this.left.poll(ctx) {
+                *this.left_out = Some(out);
+            }
+        }
+
+        if this.right_out.is_none() {
+            if let Poll::Ready(out) = this.right.poll(ctx) {
+                *this.right_out = Some(out);
+            }
+        }
+
+        if this.left_out.is_some() && this.right_out.is_some() {
+            Poll::Ready((this.left_out.take().unwrap(), this.right_out.take().unwrap()))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<L, R> Future for futs::EitherOrBoth<L, R>
+where
+    L: Future,
+    R: Future,
+{
+    type Output = EitherOrBoth<L::Output, R::Output>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let left = this.left.poll(ctx);
+        let right = this.right.poll(ctx);
+
+        match (left, right) {
+            (Poll::Ready(left), Poll::Ready(right)) => Poll::Ready(EitherOrBoth::Both(left, right)),
+            (Poll::Ready(left), Poll::Pending) => Poll::Ready(EitherOrBoth::Left(left)),
+            (Poll::Pending, Poll::Ready(right)) => Poll::Ready(EitherOrBoth::Right(right)),
+            (Poll::Pending, Poll::Pending) => Poll::Pending,
+        }
+    }
+}
+
+impl<L, R, FL, FR> futs::TryEitherRecover<L, R, FL, FR> {
+    /// Returns the same future as [`try_either_recover()`], but taking futures directly, which
+    /// allows to use it in const contexts.
+    pub const fn new(left: L, right: R, recover_left: FL, recover_right: FR) -> Self {
+        futs::TryEitherRecover { left, right, recover_left, recover_right }
+    }
+}
+
+impl<OL, OR, E, L, R, FL, FR> Future for futs::TryEitherRecover<L, R, FL, FR>
+where
+    L: Future<Output = Result<OL, E>>,
+    R: Future<Output = Result<OR, E>>,
+    FL: FnMut(E) -> Result<L, E>,
+    FR: FnMut(E) -> Result<R, E>,
+{
+    type Output = Result<Either<OL, OR>, E>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let mut left = this.left;
+        while let Poll::Ready(out) = left.as_mut().poll(ctx) {
+            match out.map_err(&mut *this.recover_left) {
+                Ok(out) => return Poll::Ready(Ok(Either::Left(out))),
+                Err(Ok(fut)) => left.set(fut),
+                Err(Err(err)) => return Poll::Ready(Err(err)),
+            }
+        }
+
+        let mut right = this.right;
+        while let Poll::Ready(out) = right.as_mut().poll(ctx) {
+            match out.map_err(&mut *this.recover_right) {
+                Ok(out) => return Poll::Ready(Ok(Either::Right(out))),
+                Err(Ok(fut)) => right.set(fut),
+                Err(Err(err)) => return Poll::Ready(Err(err)),
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<L, R> futs::TryEitherGeneric<L, R> {
+    /// Returns the same future as [`try_either_generic()`], but taking futures directly, which
+    /// allows to use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::TryEitherGeneric { fut: futs::Either::new(left, right) }
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<L, R> Future for futs::TryEitherGeneric<L, R>
+where
+    L: Future,
+    R: Future,
+    L::Output: Try,
+    R::Output: Try<Residual = <L::Output as Try>::Residual>,
+    <L::Output as Try>::Residual:
+        Residual<Either<<L::Output as Try>::Output, <R::Output as Try>::Output>>,
+{
+    type Output = <<L::Output as Try>::Residual as Residual<
+        Either<<L::Output as Try>::Output, <R::Output as Try>::Output>,
+    >>::TryType;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            let out: Self::Output = match out {
+                Either::Left(left) => match left.branch() {
+                    ControlFlow::Continue(left) => Try::from_output(Either::Left(left)),
+                    ControlFlow::Break(residual) => FromResidual::from_residual(residual),
+                },
+                Either::Right(right) => match right.branch() {
+                    ControlFlow::Continue(right) => Try::from_output(Either::Right(right)),
+                    ControlFlow::Break(residual) => FromResidual::from_residual(residual),
+                },
+            };
+
+            Poll::Ready(out)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<L, R> futs::TryEitherPreferOk<L, R> {
+    /// Returns the same future as [`try_either_prefer_ok()`], but taking futures directly, which
+    /// allows to use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::TryEitherPreferOk { fut: either_or_both(left, right) }
+    }
+}
+
+impl<OL, OR, E, L, R> Future for futs::TryEitherPreferOk<L, R>
+where
+    L: Future<Output = Result<OL, E>>,
+    R: Future<Output = Result<OR, E>>,
+{
+    type Output = Result<Either<OL, OR>, E>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            match out {
+                EitherOrBoth::Left(Ok(left)) | EitherOrBoth::Both(Ok(left), _) => {
+                    Ok(Either::Left(left))
+                }
+                EitherOrBoth::Right(Ok(right)) | EitherOrBoth::Both(Err(_), Ok(right)) => {
+                    Ok(Either::Right(right))
+                }
+                EitherOrBoth::Left(Err(err))
+                | EitherOrBoth::Right(Err(err))
+                | EitherOrBoth::Both(Err(err), Err(_)) => Err(err),
+            }.into()
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<T, L, R, FL, FR> Future for futs::EitherMap<L, R, FL, FR>
+where
+    L: Future,
+    R: Future,
+    FL: FnOnce(L::Output) -> T,
+    FR: FnOnce(R::Output) -> T,
+{
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            match out {
+                Either::Left(left) => {
+                    (this.map_left.take().expect("polled after completion"))(left)
+                }
+                Either::Right(right) => {
+                    (this.map_right.take().expect("polled after completion"))(right)
+                }
+            }.into()
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<T, OL, OR, F> Future for futs::Unify<F, T>
+where
+    F: Future<Output = Either<OL, OR>>,
+    OL: Into<T>,
+    OR: Into<T>,
+{
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            Poll::Ready(match out {
+                Either::Left(left) => left.into(),
+                Either::Right(right) => right.into(),
+            })
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<L, R, F> futs::Fallback<L, R, F> {
+    /// Returns the same future as [`fallback()`], but taking futures directly, which allows to use
+    /// it in const contexts.
+    pub const fn new(left: L, make_right: F) -> Self {
+        futs::Fallback { left, right: None, make_right: Some(make_right) }
+    }
+}
+
+impl<OL, OR, EL, ER, L, R, F> Future for futs::Fallback<L, R, F>
+where
+    L: Future<Output = Result<OL, EL>>,
+    R: Future<Output = Result<OR, ER>>,
+    F: FnOnce(EL) -> R,
+{
+    type Output = Result<Either<OL, OR>, ER>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut right = this.right;
+
+        if right.is_none() {
+            match this.left.poll(ctx) {
+                Poll::Ready(Ok(out)) => return Poll::Ready(Ok(Either::Left(out))),
+                Poll::Ready(Err(err)) => {
+                    let make_right = this.make_right.take().expect("polled after completion");
+                    right.set(Some(make_right(err)));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        if let Some(fut) = right.as_pin_mut() {
+            if let Poll::Ready(out) = fut.poll(ctx) {
+                return Poll::Ready(out.map(Either::Right));
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<L, D, R, F> futs::FallbackAfter<L, D, R, F> {
+    /// Returns the same future as [`fallback_after()`], but taking futures directly, which allows
+    /// to use it in const contexts.
+    pub const fn new(left: L, delay: D, make_right: F) -> Self {
+        futs::FallbackAfter { left, delay: Some(delay), right: None, make_right: Some(make_right) }
+    }
+}
+
+impl<L, D, R, F> Future for futs::FallbackAfter<L, D, R, F>
+where
+    L: Future,
+    D: Future<Output = ()>,
+    R: Future,
+    F: FnOnce() -> R,
+{
+    type Output = Either<L::Output, R::Output>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        if let Poll::Ready(out) = this.left.poll(ctx) {
+            return Poll::Ready(Either::Left(out));
+        }
+
+        let mut delay = this.delay;
+        if let Some(fut) = delay.as_mut().as_pin_mut() {
+            if fut.poll(ctx).is_pending() {
+                return Poll::Pending;
+            }
+
+            delay.set(None);
+
+            let make_right = this.make_right.take().expect("polled after completion");
+            this.right.as_mut().set(Some(make_right()));
+        }
+
+        if let Some(fut) = this.right.as_pin_mut() {
+            if let Poll::Ready(out) = fut.poll(ctx) {
+                return Poll::Ready(Either::Right(out));
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<T, F, D, Fut> Future for futs::Hedge<F, D, Fut>
+where
+    F: FnMut() -> Fut,
+    D: Future<Output = ()>,
+    Fut: Future<Output = T>,
+{
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            Poll::Ready(out.into_inner())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<L, R> futs::Branch<L, R> {
+    /// Returns the same future as [`left()`], but taking a future directly, which allows to use it
+    /// in const contexts.
+    pub const fn left(fut: L) -> Self {
+        futs::Branch { fut: Either::Left(fut) }
+    }
+
+    /// Returns the same future as [`right()`], but taking a future directly, which allows to use it
+    /// in const contexts.
+    pub const fn right(fut: R) -> Self {
+        futs::Branch { fut: Either::Right(fut) }
+    }
+}
+
+impl<L, R> Future for futs::Branch<L, R>
+where
+    L: Future,
+    R: Future<Output = L::Output>,
+{
+    type Output = L::Output;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        match self.project().fut.as_pin_mut() {
+            Either::Left(fut) => fut.poll(ctx),
+            Either::Right(fut) => fut.poll(ctx),
+        }
+    }
+}
+
+impl<OL, OR, T, F, M> Future for futs::MapLeft<F, M>
+where
+    F: Future<Output = Either<OL, OR>>,
+    M: FnOnce(OL) -> T,
+{
+    type Output = Either<T, OR>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            let map = this.map.take().expect("polled after completion");
+            Poll::Ready(out.map_left(map))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<OL, OR, T, F, M> Future for futs::MapRight<F, M>
+where
+    F: Future<Output = Either<OL, OR>>,
+    M: FnOnce(OR) -> T,
+{
+    type Output = Either<OL, T>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            let map = this.map.take().expect("polled after completion");
+            Poll::Ready(out.map_right(map))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<OL, OR, TL, TR, F, ML, MR> Future for futs::MapEither<F, ML, MR>
+where
+    F: Future<Output = Either<OL, OR>>,
+    ML: FnOnce(OL) -> TL,
+    MR: FnOnce(OR) -> TR,
+{
+    type Output = Either<TL, TR>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            let map_left = this.map_left.take().expect("polled after completion");
+            let map_right = this.map_right.take().expect("polled after completion");
+            Poll::Ready(out.map_either(map_left, map_right))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<OL, OR, F, I> Future for futs::InspectLeft<F, I>
+where
+    F: Future<Output = Either<OL, OR>>,
+    I: FnOnce(&OL),
+{
+    type Output = Either<OL, OR>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            let inspect = this.inspect.take().expect("polled after completion");
+            if let Either::Left(out) = &out {
+                inspect(out);
+            }
+
+            Poll::Ready(out)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<OL, OR, F, I> Future for futs::InspectRight<F, I>
+where
+    F: Future<Output = Either<OL, OR>>,
+    I: FnOnce(&OR),
+{
+    type Output = Either<OL, OR>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            let inspect = this.inspect.take().expect("polled after completion");
+            if let Either::Right(out) = &out {
+                inspect(out);
+            }
+
+            Poll::Ready(out)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<OL, OR, E, F, I> Future for futs::InspectOk<F, I>
+where
+    F: Future<Output = Result<Either<OL, OR>, E>>,
+    I: FnOnce(&Either<OL, OR>),
+{
+    type Output = Result<Either<OL, OR>, E>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            let inspect = this.inspect.take().expect("polled after completion");
+            if let Ok(out) = &out {
+                inspect(out);
+            }
+
+            Poll::Ready(out)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<OL, OR, E, F, I> Future for futs::InspectErr<F, I>
+where
+    F: Future<Output = Result<Either<OL, OR>, E>>,
+    I: FnOnce(&E),
+{
+    type Output = Result<Either<OL, OR>, E>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            let inspect = this.inspect.take().expect("polled after completion");
+            if let Err(err) = &out {
+                inspect(err);
+            }
+
+            Poll::Ready(out)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<OL, OR, E, T, F, M> Future for futs::MapOk<F, M>
+where
+    F: Future<Output = Result<Either<OL, OR>, E>>,
+    M: FnOnce(Either<OL, OR>) -> T,
+{
+    type Output = Result<T, E>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            let map = this.map.take().expect("polled after completion");
+            Poll::Ready(out.map(map))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<OL, OR, E, T, F, M> Future for futs::MapErr<F, M>
+where
+    F: Future<Output = Result<Either<OL, OR>, E>>,
+    M: FnOnce(E) -> T,
+{
+    type Output = Result<Either<OL, OR>, T>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            let map = this.map.take().expect("polled after completion");
+            Poll::Ready(out.map_err(map))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<OL, OR, E, T, F, Fut, M> Future for futs::AndThen<F, Fut, M>
+where
+    F: Future<Output = Result<Either<OL, OR>, E>>,
+    Fut: Future<Output = Result<T, E>>,
+    M: FnOnce(Either<OL, OR>) -> Fut,
+{
+    type Output = Result<T, E>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        if this.then.is_none() {
+            match this.fut.poll(ctx) {
+                Poll::Ready(Ok(out)) => {
+                    let map = this.map.take().expect("polled after completion");
+                    this.then.set(Some(map(out)));
+                }
+                Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        this.then.as_pin_mut().expect("`then` should have been set").poll(ctx)
+    }
+}
+
+impl<OL, OR, F> Future for futs::Swap<F>
+where
+    F: Future<Output = Either<OL, OR>>,
+{
+    type Output = Either<OR, OL>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        this.fut.poll(ctx).map(Either::flip)
+    }
+}
+
+impl<OL, OR, E, F> Future for futs::TrySwap<F>
+where
+    F: Future<Output = Result<Either<OL, OR>, E>>,
+{
+    type Output = Result<Either<OR, OL>, E>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        this.fut.poll(ctx).map(|out| out.map(Either::flip))
+    }
+}
+
+impl<F, FL, FR> Future for futs::FlattenOutput<F, FL, FR>
+where
+    F: Future<Output = Either<FL, FR>>,
+    FL: Future,
+    FR: Future,
+{
+    type Output = Either<FL::Output, FR::Output>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        if this.inner.is_none() {
+            match this.fut.poll(ctx) {
+                Poll::Ready(inner) => this.inner.set(Some(inner)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        match this.inner.as_pin_mut().expect("`inner` should have been set").as_pin_mut() {
+            Either::Left(fut) => fut.poll(ctx).map(Either::Left),
+            Either::Right(fut) => fut.poll(ctx).map(Either::Right),
+        }
+    }
+}
+
+impl<L, R> Future for futs::Built<L, R>
+where
+    L: Future,
+    R: Future,
+{
+    type Output = Either<L::Output, R::Output>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let deferred = this.deferred.take();
+
+        #[allow(clippy::infallible_destructuring_match)]
+        let first = match (deferred, *this.priority) {
+            (Some(side), _) | (None, Priority::Biased(side)) => side,
+            #[cfg(feature = "fair-lite")]
+            (None, Priority::Fair) => Random.first(),
+        };
+
+        if !*this.one_per_poll {
+            return poll_in_order(ctx, first, this.left, this.right);
+        }
+
+        let out = match first {
+            Side::Left => this.left.poll(ctx).map(Either::Left),
+            Side::Right => this.right.poll(ctx).map(Either::Right),
+        };
+
+        // Only the future polled first wakes the task up to poll the other one: once both have
+        // been polled, they have both registered the task's waker and the next wake-up starts
+        // over from the configured side instead of busy-looping through an idle race.
+        if out.is_pending() && deferred.is_none() {
+            *this.deferred = Some(first.other());
+            ctx.waker().wake_by_ref();
+        }
+
+        out
+    }
+}
+
+impl<L, R> Future for futs::EitherAlternate<L, R>
+where
+    L: Future,
+    R: Future,
+{
+    type Output = Either<L::Output, R::Output>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        poll_in_order(ctx, this.order.first(), this.left, this.right)
+    }
+}
+
+#[cfg(feature = "fair")]
+impl<L, R> Future for futs::EitherWeighted<L, R>
+where
+    L: Future,
+    R: Future,
+{
+    type Output = Either<L::Output, R::Output>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        let first = if Random::f32() < *this.weight { Side::Left } else { Side::Right };
+        poll_in_order(ctx, first, this.left, this.right)
+    }
+}
+
+impl<P, L, R> Future for futs::EitherWith<P, L, R>
+where
+    P: PollOrder,
+    L: Future,
+    R: Future,
+{
+    type Output = Either<L::Output, R::Output>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        poll_in_order(ctx, this.order.first(), this.left, this.right)
+    }
+}
+
+impl<'s, P, L, R> Future for futs::EitherTracked<'s, P, L, R>
+where
+    P: PollOrder,
+    L: Future,
+    R: Future,
+{
+    type Output = Either<L::Output, R::Output>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        let fut = this.fut.project();
+
+        let first = fut.order.first();
+        let out = poll_in_order(ctx, first, fut.left, fut.right);
+
+        let mut stats = this.stats.get();
+        match first {
+            Side::Left => stats.left_first += 1,
+            Side::Right => stats.right_first += 1,
+        }
+
+        match out {
+            Poll::Ready(Either::Left(_)) => stats.left_wins += 1,
+            Poll::Ready(Either::Right(_)) => stats.right_wins += 1,
+            Poll::Pending => (),
+        }
+
+        this.stats.set(stats);
+        out
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<L, R> Future for futs::EitherTagged<L, R>
+where
+    L: Future,
+    R: Future,
+{
+    type Output = Either<L::Output, R::Output>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let (mut poll_left, mut poll_right) = match &this.tags {
+            Some([left, right]) if left.parent.will_wake(ctx.waker()) => (
+                left.woken.swap(false, Ordering::AcqRel),
+                right.woken.swap(false, Ordering::AcqRel),
+            ),
+            _ => {
+                *this.tags = Some([WakeTag::new(ctx.waker()), WakeTag::new(ctx.waker())]);
+                (true, true)
+            }
+        };
+
+        if !poll_left && !poll_right {
+            poll_left = true;
+            poll_right = true;
+        }
+
+        let [left_tag, right_tag] = this.tags.as_ref().expect("`tags` should have been set");
+
+        if poll_left {
+            let waker = Waker::from(left_tag.clone());
+            let fut = this.left;
+            if let Poll::Ready(out) = fut.poll(&mut Context::from_waker(&waker)) {
+                return Poll::Ready(Either::Left(out));
+            }
+        }
+
+        if poll_right {
+            let waker = Waker::from(right_tag.clone());
+            let fut = this.right;
+            if let Poll::Ready(out) = fut.poll(&mut Context::from_waker(&waker)) {
+                return Poll::Ready(Either::Right(out));
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl WakeTag {
+    fn new(parent: &Waker) -> Arc<Self> {
+        Arc::new(WakeTag { woken: AtomicBool::new(false), parent: parent.clone() })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Wake for WakeTag {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.store(true, Ordering::Release);
+        self.parent.wake_by_ref();
+    }
+}
+
+impl<F, D> Future for futs::Timeout<F, D>
+where
+    F: Future,
+    D: Future,
+{
+    type Output = Result<F::Output, Elapsed>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = this.fut.poll(ctx) {
+            return Poll::Ready(Ok(out));
+        }
+
+        if this.delay.poll(ctx).is_ready() {
+            return Poll::Ready(Err(Elapsed));
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<L, R> Future for futs::Select<'_, L, R>
+where
+    L: Future + Unpin,
+    R: Future + Unpin,
+{
+    type Output = Option<Either<L::Output, R::Output>>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let select = &mut *self.select;
+        if select.is_empty() {
+            return Poll::Ready(None);
+        }
+
+        let first = select.first;
+        for side in [first, first.other()] {
+            let out = match side {
+                Side::Left => match &mut select.left {
+                    Some(fut) => Pin::new(fut).poll(ctx).map(Either::Left),
+                    None => continue,
+                },
+                Side::Right => match &mut select.right {
+                    Some(fut) => Pin::new(fut).poll(ctx).map(Either::Right),
+                    None => continue,
+                },
+            };
+
+            if let Poll::Ready(out) = out {
+                match side {
+                    Side::Left => select.left = None,
+                    Side::Right => select.right = None,
+                }
+
+                select.first = first.other();
+                return Poll::Ready(Some(out));
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<L, R, CL, CR, FL, FR> Future
+    for futs::EitherGraceful<L, R, CL, CR, FL, FR, L::Output, R::Output>
+where
+    L: Future,
+    R: Future,
+    CL: FnOnce() -> FL,
+    CR: FnOnce() -> FR,
+    FL: Future,
+    FR: Future,
+{
+    type Output = Either<L::Output, R::Output>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        if this.out.is_none() {
+            let left = this.left.as_mut().as_pin_mut().expect("polled after completion");
+            let right = this.right.as_mut().as_pin_mut().expect("polled after completion");
+            let out = match poll_in_order(ctx, Side::Left, left, right) {
+                Poll::Ready(out) => out,
+                Poll::Pending => return Poll::Pending,
+            };
+
+            let cancel = match out {
+                Either::Left(_) => {
+                    this.left.set(None);
+                    Either::Right((this.cancel_right.take().expect("polled after completion"))())
+                }
+                Either::Right(_) => {
+                    this.right.set(None);
+                    Either::Left((this.cancel_left.take().expect("polled after completion"))())
+                }
+            };
+
+            this.cancel.set(Some(cancel));
+            *this.out = Some(out);
+        }
+
+        // Keeps polling the other future (if it hasn't completed in the meantime), so that it can
+        // react to its cancellation.
+        if let Some(fut) = this.left.as_mut().as_pin_mut() {
+            if fut.poll(ctx).is_ready() {
+                this.left.set(None);
+            }
+        }
+
+        if let Some(fut) = this.right.as_mut().as_pin_mut() {
+            if fut.poll(ctx).is_ready() {
+                this.right.set(None);
+            }
+        }
+
+        let cancel = this.cancel.as_mut().as_pin_mut().expect("`cancel` should have been set");
+        let cancelled = match cancel.as_pin_mut() {
+            Either::Left(fut) => fut.poll(ctx).is_ready(),
+            Either::Right(fut) => fut.poll(ctx).is_ready(),
+        };
+
+        if !cancelled {
+            return Poll::Pending;
+        }
+
+        this.left.set(None);
+        this.right.set(None);
+        this.cancel.set(None);
+        Poll::Ready(this.out.take().expect("polled after completion"))
+    }
+}
+
+impl<L, R> Future for futs::EitherRemainder<L, R>
+where
+    L: Future + Unpin,
+    R: Future + Unpin,
+{
+    type Output = Either<(L::Output, R), (L, R::Output)>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let (left, right) = self.futs.as_mut().expect("polled after completion");
+        let out = match poll_in_order(ctx, Side::Left, Pin::new(left), Pin::new(right)) {
+            Poll::Ready(out) => out,
+            Poll::Pending => return Poll::Pending,
+        };
+
+        let (left, right) = self.futs.take().expect("polled after completion");
+        Poll::Ready(match out {
+            Either::Left(out) => Either::Left((out, right)),
+            Either::Right(out) => Either::Right((left, out)),
+        })
+    }
+}
+
+impl<L, R, S> Future for futs::EitherDetach<L, R, S>
+where
+    L: Future + Unpin,
+    R: Future + Unpin,
+    S: Spawn<L> + Spawn<R>,
+{
+    type Output = Either<L::Output, R::Output>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        let spawner = this.spawner;
+
+        Pin::new(this.fut).poll(ctx).map(|out| match out {
+            Either::Left((out, right)) => {
+                spawner.spawn(right);
+                Either::Left(out)
+            }
+            Either::Right((left, out)) => {
+                spawner.spawn(left);
+                Either::Right(out)
+            }
+        })
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<L, R> Future for futs::EitherTasks<L, R> {
+    type Output = Result<Either<L, R>, tokio::task::JoinError>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let left = Pin::new(&mut this.left);
+        let right = Pin::new(&mut this.right);
+
+        let out = poll_try_in_order(ctx, Side::Left, left, right);
+        if out.is_ready() {
+            // Aborting a task which has already completed does nothing.
+            this.left.abort();
+            this.right.abort();
+        }
+
+        out
+    }
+}
+
+#[cfg(feature = "spawned")]
+impl<L, R> Future for futs::EitherSpawned<L, R> {
+    type Output = Result<Either<L, R>, futures_channel::oneshot::Canceled>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let left = Pin::new(&mut this.left);
+        let right = Pin::new(&mut this.right);
+
+        let out = poll_try_in_order(ctx, Side::Left, left, right);
+        if out.is_ready() {
+            // Makes the task of the other future stop (closing the receiver of the future which
+            // completed does nothing).
+            this.left.close();
+            this.right.close();
+        }
+
+        out
+    }
+}
+
+#[cfg(feature = "spawned")]
+impl<F> Future for futs::Spawned<F>
+where
+    F: Future,
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let tx = this.tx.as_mut().expect("polled after completion");
+        if tx.poll_canceled(ctx).is_ready() {
+            *this.tx = None;
+            return Poll::Ready(());
+        }
+
+        match this.fut.poll(ctx) {
+            Poll::Ready(out) => {
+                let tx = this.tx.take().expect("`tx` should have been set");
+                // The receiver might have been dropped in the meantime.
+                let _ = tx.send(out);
+
+                Poll::Ready(())
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(feature = "abortable")]
+impl<F> Future for futs::Abortable<F>
 where
-    L: Future,
-    R: Future,
+    F: Future,
 {
-    futs::EitherFair { left, right }
+    type Output = Result<F::Output, Aborted>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        // Registers the waker before checking whether the future has been aborted, so that a call
+        // to `abort()` in between still wakes up the task.
+        this.state.waker.register(ctx.waker());
+        if this.state.aborted.load(Ordering::Acquire) {
+            this.fut.set(None);
+            return Poll::Ready(Err(Aborted));
+        }
+
+        let fut = this.fut.as_mut().as_pin_mut().expect("polled after completion");
+        if let Poll::Ready(out) = fut.poll(ctx) {
+            this.fut.set(None);
+            return Poll::Ready(Ok(out));
+        }
+
+        Poll::Pending
+    }
 }
 
-// ======================================== try_either() ======================================== \\
+#[cfg(feature = "std")]
+impl<L, R> Future for futs::EitherCatchUnwind<L, R>
+where
+    L: Future + UnwindSafe,
+    R: Future + UnwindSafe,
+{
+    type Output = Result<Either<L::Output, R::Output>, Either<Panic, Panic>>;
 
-/// Returns a future polling two futures and returning a result with the output or error returned
-/// by the first one to complete.
-///
-/// The returned future will always poll `left` first; for a "fair" alternative, see
-/// [`try_either_fair()`].
-///
-/// ## Example
-///
-/// ```rust
-/// use futures_lite::future;
-/// use futures_either::{try_either, Either};
-///
-/// # future::block_on(async {
-/// #
-/// let out = try_either(
-///     async { Ok(42) },
-///     async { Result::<bool, bool>::Err(false) },
-/// ).await;
-/// assert_eq!(out, Ok(Either::Left(42)));
-///
-/// let out = try_either(
-///     future::pending::<Result<bool, i32>>(),
-///     async { Result::<i32, i32>::Err(42) },
-/// ).await;
-/// assert_eq!(out, Err(42));
-/// #
-/// # });
-/// ```
-pub fn try_either<OL, OR, E, L, R>(left: L, right: R) -> futs::TryEither<L, R>
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = poll_catch_unwind(this.left, ctx) {
+            return Poll::Ready(out.map(Either::Left).map_err(Either::Left));
+        }
+
+        if let Poll::Ready(out) = poll_catch_unwind(this.right, ctx) {
+            return Poll::Ready(out.map(Either::Right).map_err(Either::Right));
+        }
+
+        Poll::Pending
+    }
+}
+
+#[cfg(feature = "std")]
+impl<OL, OR, E, L, R> Future for futs::TryEitherCatchUnwind<L, R>
 where
-    L: Future<Output = Result<OL, E>>,
-    R: Future<Output = Result<OR, E>>,
+    L: Future<Output = Result<OL, E>> + UnwindSafe,
+    R: Future<Output = Result<OR, E>> + UnwindSafe,
 {
-    futs::TryEither { fut: either(left, right), }
+    type Output = Result<Either<OL, OR>, CatchUnwindError<E>>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(out) = poll_catch_unwind(this.left, ctx) {
+            return Poll::Ready(match out {
+                Ok(Ok(out)) => Ok(Either::Left(out)),
+                Ok(Err(err)) => Err(CatchUnwindError::Err(err)),
+                Err(panic) => Err(CatchUnwindError::Panic(Either::Left(panic))),
+            });
+        }
+
+        if let Poll::Ready(out) = poll_catch_unwind(this.right, ctx) {
+            return Poll::Ready(match out {
+                Ok(Ok(out)) => Ok(Either::Right(out)),
+                Ok(Err(err)) => Err(CatchUnwindError::Err(err)),
+                Err(panic) => Err(CatchUnwindError::Panic(Either::Right(panic))),
+            });
+        }
+
+        Poll::Pending
+    }
 }
 
-// ====================================== try_either_fair() ===================================== \\
+/// Polls `fut`, returning the payload of the panic instead if it panics.
+#[cfg(feature = "std")]
+fn poll_catch_unwind<F>(fut: Pin<&mut F>, ctx: &mut Context) -> Poll<Result<F::Output, Panic>>
+where
+    F: Future + UnwindSafe,
+{
+    match catch_unwind(AssertUnwindSafe(|| fut.poll(ctx))) {
+        Ok(Poll::Ready(out)) => Poll::Ready(Ok(out)),
+        Ok(Poll::Pending) => Poll::Pending,
+        Err(panic) => Poll::Ready(Err(panic)),
+    }
+}
 
-#[cfg(feature = "fair")]
-#[cfg_attr(docsrs, doc(cfg(feature = "fair")))]
-/// Returns a future polling two futures and returning a result with the ouput or error returned by
-/// the first one to complete.
-///
-/// The returned future will choose which future to poll first randomly, each time it is being
-/// polled; for an "unfair" alternative, see [`try_either()`].
-///
-/// ## Example
-///
-/// ```rust
-/// use futures_lite::future;
-/// use futures_either::{try_either_fair, Either};
-///
-/// # future::block_on(async {
-/// #
-/// let out = try_either_fair(
-///     async { Ok(42) },
-///     async { Result::<bool, bool>::Err(false) },
-/// ).await;
-/// assert!(out == Ok(Either::Left(42)) || out == Err(false));
-///
-/// let out = try_either_fair(
-///     future::pending::<Result<bool, i32>>(),
-///     async { Result::<i32, i32>::Err(42) },
-/// ).await;
-/// assert_eq!(out, Err(42));
-/// #
-/// # });
-/// ```
-pub fn try_either_fair<OL, OR, E, L, R>(left: L, right: R) -> futs::TryEitherFair<L, R>
+impl<OL, OR, E, L, R, ML, MR> Future for futs::TryEitherSupervised<L, R, ML, MR>
 where
     L: Future<Output = Result<OL, E>>,
     R: Future<Output = Result<OR, E>>,
+    ML: FnMut(usize) -> L,
+    MR: FnMut(usize) -> R,
 {
-    futs::TryEitherFair { fut: either_fair(left, right), }
+    type Output = Result<Either<OL, OR>, E>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        let max = *this.max_restarts;
+
+        let restarts = this.left_restarts;
+        if let Poll::Ready(out) = poll_supervised(ctx, this.left, this.make_left, restarts, max) {
+            return Poll::Ready(out.map(Either::Left));
+        }
+
+        let restarts = this.right_restarts;
+        if let Poll::Ready(out) = poll_supervised(ctx, this.right, this.make_right, restarts, max) {
+            return Poll::Ready(out.map(Either::Right));
+        }
+
+        Poll::Pending
+    }
 }
 
-// ========================================= impl Future ======================================== \\
+/// Polls `fut`, replacing it with a new future created by `make` and polling it again each time
+/// it returns an error, until it has been restarted `max_restarts` times.
+fn poll_supervised<O, E, F, M>(
+    ctx: &mut Context,
+    mut fut: Pin<&mut F>,
+    make: &mut M,
+    restarts: &mut usize,
+    max_restarts: usize,
+) -> Poll<Result<O, E>>
+where
+    F: Future<Output = Result<O, E>>,
+    M: FnMut(usize) -> F,
+{
+    loop {
+        match fut.as_mut().poll(ctx) {
+            Poll::Ready(Err(_)) if *restarts < max_restarts => {
+                *restarts += 1;
+                fut.set(make(*restarts));
+            }
+            out => return out,
+        }
+    }
+}
 
-impl<L, R> Future for futs::Either<L, R>
+impl<L, R, ML, MR> Future for futs::EitherLazy<L, R, ML, MR>
 where
     L: Future,
     R: Future,
+    ML: FnOnce() -> L,
+    MR: FnOnce() -> R,
 {
     type Output = Either<L::Output, R::Output>;
 
     fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
-        let this = unsafe { self.get_unchecked_mut() };
+        let this = self.project();
+        let mut fut = this.fut;
 
-        if let Poll::Ready(out) = unsafe { Pin::new_unchecked(&mut this.left) }.poll(ctx) {
-            return Poll::Ready(Either::Left(out));
+        if fut.is_none() {
+            let make_left = this.make_left.take().expect("polled after completion");
+            let make_right = this.make_right.take().expect("polled after completion");
+            fut.set(Some(futs::Either::new(make_left(), make_right())));
         }
 
-        if let Poll::Ready(out) = unsafe { Pin::new_unchecked(&mut this.right) }.poll(ctx) {
-            return Poll::Ready(Either::Right(out));
+        fut.as_pin_mut().expect("`fut` should have been set").poll(ctx)
+    }
+}
+
+impl<L, R> futs::RaceIndexed<L, R> {
+    /// Returns the same future as [`race_indexed()`], but taking futures directly, which allows to
+    /// use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::RaceIndexed { fut: futs::Either::new(left, right) }
+    }
+}
+
+impl<T, L, R> Future for futs::RaceIndexed<L, R>
+where
+    L: Future<Output = T>,
+    R: Future<Output = T>,
+{
+    type Output = (Side, T);
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        match this.fut.poll(ctx) {
+            Poll::Ready(Either::Left(out)) => Poll::Ready((Side::Left, out)),
+            Poll::Ready(Either::Right(out)) => Poll::Ready((Side::Right, out)),
+            Poll::Pending => Poll::Pending,
         }
+    }
+}
 
-        Poll::Pending
+#[cfg(feature = "fair-lite")]
+impl<L, R> futs::RaceIndexedFair<L, R> {
+    /// Returns the same future as [`race_indexed_fair()`], but taking futures directly, which
+    /// allows to use it in const contexts.
+    pub const fn new(left: L, right: R) -> Self {
+        futs::RaceIndexedFair { fut: futs::EitherFair::new(left, right) }
     }
 }
 
-#[cfg(feature = "fair")]
-impl<L, R> Future for futs::EitherFair<L, R>
+#[cfg(feature = "fair-lite")]
+impl<T, L, R> Future for futs::RaceIndexedFair<L, R>
+where
+    L: Future<Output = T>,
+    R: Future<Output = T>,
+{
+    type Output = (Side, T);
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        match this.fut.poll(ctx) {
+            Poll::Ready(Either::Left(out)) => Poll::Ready((Side::Left, out)),
+            Poll::Ready(Either::Right(out)) => Poll::Ready((Side::Right, out)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<L, R> Future for futs::EitherFuture<L, R>
 where
     L: Future,
     R: Future,
@@ -273,71 +10792,262 @@ where
     type Output = Either<L::Output, R::Output>;
 
     fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
-        let this = unsafe { self.get_unchecked_mut() };
+        match self.project().fut.as_pin_mut() {
+            Either::Left(fut) => fut.poll(ctx).map(Either::Left),
+            Either::Right(fut) => fut.poll(ctx).map(Either::Right),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F> Future for futs::Cache<F, F::Output>
+where
+    F: Future,
+    F::Output: Clone,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let mut fut = match lock(&self.state).take(ctx.waker()) {
+            Ok(fut) => fut,
+            Err(out) => return out,
+        };
 
-        if fastrand::bool() {
-            if let Poll::Ready(out) = unsafe { Pin::new_unchecked(&mut this.left) }.poll(ctx) {
-                return Poll::Ready(Either::Left(out));
-            }
+        let out = fut.as_mut().poll(ctx);
+        let (out, wakers) = lock(&self.state).put_back(fut, out, ctx.waker());
+        wakers.into_iter().for_each(Waker::wake);
 
-            if let Poll::Ready(out) = unsafe { Pin::new_unchecked(&mut this.right) }.poll(ctx) {
-                return Poll::Ready(Either::Right(out));
-            }
-        } else {
-            if let Poll::Ready(out) = unsafe { Pin::new_unchecked(&mut this.right) }.poll(ctx) {
-                return Poll::Ready(Either::Right(out));
+        out
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F> Future for futs::CacheLocal<F, F::Output>
+where
+    F: Future,
+    F::Output: Clone,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let mut fut = match RefCell::borrow_mut(&self.state).take(ctx.waker()) {
+            Ok(fut) => fut,
+            Err(out) => return out,
+        };
+
+        let out = fut.as_mut().poll(ctx);
+        let (out, wakers) = RefCell::borrow_mut(&self.state).put_back(fut, out, ctx.waker());
+        wakers.into_iter().for_each(Waker::wake);
+
+        out
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F, T> CacheState<F, T>
+where
+    F: Future<Output = T>,
+    T: Clone,
+{
+    fn new(fut: F) -> Self {
+        CacheState { fut: Some(Box::pin(fut)), out: None, wakers: Vec::new() }
+    }
+
+    /// Takes the future out to poll it, or returns what the handle polled with `waker` should
+    /// return if the output is already available or another handle is already polling it.
+    fn take(&mut self, waker: &Waker) -> Result<Pin<Box<F>>, Poll<T>> {
+        if let Some(out) = &self.out {
+            return Err(Poll::Ready(out.clone()));
+        }
+
+        self.fut.take().ok_or_else(|| {
+            self.register(waker);
+            Poll::Pending
+        })
+    }
+
+    /// Puts the future back after it returned `out` when polled with `waker`, returning what the
+    /// handle should return and the wakers to wake up once the state has been released.
+    fn put_back(&mut self, fut: Pin<Box<F>>, out: Poll<T>, waker: &Waker) -> (Poll<T>, Vec<Waker>) {
+        match out {
+            Poll::Ready(out) => {
+                self.out = Some(out.clone());
+                (Poll::Ready(out), mem::take(&mut self.wakers))
             }
-           
-            if let Poll::Ready(out) = unsafe { Pin::new_unchecked(&mut this.left) }.poll(ctx) {
-                return Poll::Ready(Either::Left(out));
+            Poll::Pending => {
+                self.fut = Some(fut);
+                self.register(waker);
+                (Poll::Pending, Vec::new())
             }
         }
+    }
 
-        Poll::Pending
+    fn register(&mut self, waker: &Waker) {
+        if !self.wakers.iter().any(|other| other.will_wake(waker)) {
+            self.wakers.push(waker.clone());
+        }
     }
 }
 
-impl<OL, OR, E, L, R> Future for futs::TryEither<L, R>
+/// Locks `state`, ignoring poisoning as it is left consistent even if a handle panics.
+#[cfg(feature = "std")]
+fn lock<T>(state: &Mutex<T>) -> MutexGuard<'_, T> {
+    state.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl<F, P> Future for futs::AfterReady<F, P>
 where
-    L: Future<Output = Result<OL, E>>,
-    R: Future<Output = Result<OR, E>>,
+    F: Future,
+    P: AfterReady<F::Output>,
 {
-    type Output = Result<Either<OL, OR>, E>;
+    type Output = F::Output;
 
     fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
-        let this = unsafe { self.get_unchecked_mut() };
+        let mut this = self.project();
 
-        if let Poll::Ready(out) = unsafe { Pin::new_unchecked(&mut this.fut) }.poll(ctx) {
-            match out {
-                Either::Left(Ok(left)) => Ok(Either::Left(left)),
-                Either::Right(Ok(right)) => Ok(Either::Right(right)),
-                Either::Left(Err(err)) | Either::Right(Err(err)) => Err(err),
-            }.into()
+        let fut = if let Some(fut) = this.fut.as_mut().as_pin_mut() {
+            fut
+        } else {
+            return this.policy.poll_again();
+        };
+
+        if let Poll::Ready(out) = fut.poll(ctx) {
+            this.fut.set(None);
+            this.policy.ready(&out);
+            Poll::Ready(out)
         } else {
             Poll::Pending
         }
     }
 }
 
-#[cfg(feature = "fair")]
-impl<OL, OR, E, L, R> Future for futs::TryEitherFair<L, R>
+#[cfg(feature = "stream")]
+impl<L, R, ML, MR> Stream for futs::Alternate<L, R, ML, MR>
+where
+    L: Future,
+    R: Future,
+    ML: FnMut() -> L,
+    MR: FnMut() -> R,
+{
+    type Item = Either<L::Output, R::Output>;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let mut fut = this.fut;
+
+        if fut.is_none() {
+            fut.set(Some(match this.next {
+                Side::Left => Either::Left((this.make_left)()),
+                Side::Right => Either::Right((this.make_right)()),
+            }));
+        }
+
+        let out = match fut.as_mut().as_pin_mut().expect("`fut` should have been set").as_pin_mut() {
+            Either::Left(fut) => fut.poll(ctx).map(Either::Left),
+            Either::Right(fut) => fut.poll(ctx).map(Either::Right),
+        };
+
+        if out.is_ready() {
+            fut.set(None);
+            *this.next = this.next.other();
+        }
+
+        out.map(Some)
+    }
+}
+
+// ====================================== impl FusedFuture ====================================== \\
+
+#[cfg(feature = "futures-core")]
+impl<L, R> FusedFuture for futs::Either<L, R>
+where
+    L: Future,
+    R: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(all(feature = "futures-core", feature = "fair-lite"))]
+impl<L, R> FusedFuture for futs::EitherFair<L, R>
+where
+    L: Future,
+    R: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(feature = "futures-core")]
+impl<OL, OR, E, L, R> FusedFuture for futs::TryEither<L, R>
 where
     L: Future<Output = Result<OL, E>>,
     R: Future<Output = Result<OR, E>>,
 {
-    type Output = Result<Either<OL, OR>, E>;
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
 
-    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
-        let this = unsafe { self.get_unchecked_mut() };
+#[cfg(all(feature = "futures-core", feature = "fair-lite"))]
+impl<OL, OR, E, L, R> FusedFuture for futs::TryEitherFair<L, R>
+where
+    L: Future<Output = Result<OL, E>>,
+    R: Future<Output = Result<OR, E>>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
 
-        if let Poll::Ready(out) = unsafe { Pin::new_unchecked(&mut this.fut) }.poll(ctx) {
-            match out {
-                Either::Left(Ok(left)) => Ok(Either::Left(left)),
-                Either::Right(Ok(right)) => Ok(Either::Right(right)),
-                Either::Left(Err(err)) | Either::Right(Err(err)) => Err(err),
-            }.into()
-        } else {
-            Poll::Pending
+// =========================================== Tests ============================================ \\
+
+#[cfg(all(test, debug_assertions, feature = "std"))]
+mod tests {
+    use core::cell::Cell;
+    use core::future::{self, Future};
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+
+    use crate::{either, set_busy_wake_hook, set_busy_wake_threshold};
+
+    std::thread_local! {
+        static REPORTS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn report(_: &'static str) {
+        REPORTS.with(|reports| reports.set(reports.get() + 1));
+    }
+
+    fn poll_pending<F: Future>(fut: F, polls: usize) -> usize {
+        set_busy_wake_threshold(16);
+        set_busy_wake_hook(report);
+
+        let mut fut = pin!(fut);
+        let mut ctx = Context::from_waker(Waker::noop());
+        for _ in 0..polls {
+            assert!(fut.as_mut().poll(&mut ctx).is_pending());
         }
+
+        REPORTS.with(Cell::get)
+    }
+
+    #[test]
+    fn busy_wake_is_reported_once() {
+        let spin = future::poll_fn(|ctx| {
+            ctx.waker().wake_by_ref();
+            Poll::<bool>::Pending
+        });
+
+        assert_eq!(poll_pending(either(spin, future::pending::<i32>()), 64), 1);
+    }
+
+    #[test]
+    fn long_race_is_not_reported() {
+        let race = either(future::pending::<bool>(), future::pending::<i32>());
+
+        assert_eq!(poll_pending(race, 64), 0);
     }
 }
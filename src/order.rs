@@ -0,0 +1,547 @@
+/**************************************************************************************************
+ *                                                                                                *
+ * This Source Code Form is subject to the terms of the Mozilla Public                            *
+ * License, v. 2.0. If a copy of the MPL was not distributed with this                            *
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.                                       *
+ *                                                                                                *
+ **************************************************************************************************/
+
+// ======================================== Documentation ======================================= \\
+
+//! Policies deciding which of two raced futures gets polled first, to be used with
+//! [`either_with()`].
+//!
+//! ## Example
+//!
+//! ```rust
+//! use futures_lite::future;
+//! use futures_either::order::{PollOrder, RightFirst};
+//! use futures_either::{either_with, Either, Side};
+//!
+//! /// Polls the right future first every third poll.
+//! struct EveryThird(u8);
+//!
+//! impl PollOrder for EveryThird {
+//!     fn first(&mut self) -> Side {
+//!         self.0 = (self.0 + 1) % 3;
+//!         if self.0 == 0 { Side::Right } else { Side::Left }
+//!     }
+//! }
+//!
+//! # future::block_on(async {
+//! #
+//! let out = either_with(async { 42 }, async { false }, RightFirst).await;
+//! assert_eq!(out, Either::Right(false));
+//!
+//! let out = either_with(async { 42 }, async { false }, EveryThird(0)).await;
+//! assert_eq!(out, Either::Left(42));
+//! #
+//! # });
+//! ```
+//!
+//! [`either_with()`]: crate::either_with()
+
+// =========================================== Imports ========================================== \\
+
+use crate::Side;
+#[cfg(feature = "fair")]
+use core::cell::Cell;
+#[cfg(all(feature = "fair-lite", not(feature = "fair")))]
+use core::sync::atomic::AtomicBool;
+#[cfg(feature = "fair-lite")]
+use core::sync::atomic::AtomicUsize;
+#[cfg(feature = "fair-lite")]
+use core::sync::atomic::Ordering;
+#[cfg(all(feature = "fair-lite", feature = "std"))]
+use std::sync::{PoisonError, RwLock};
+
+// ============================================ Types =========================================== \\
+
+/// A policy deciding which of two raced futures gets polled first, each time they get polled.
+pub trait PollOrder {
+    /// Returns the side of the future to poll first.
+    fn first(&mut self) -> Side;
+}
+
+/// Always polls the left future first, as [`either()`] does.
+///
+/// [`either()`]: crate::either()
+#[derive(Copy, Clone, Default, Debug)]
+pub struct LeftFirst;
+
+/// Always polls the right future first.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct RightFirst;
+
+/// Always polls the left future first if `LEFT_FIRST` is `true`, and the right one otherwise, as
+/// [`either_biased_const()`] does.
+///
+/// [`either_biased_const()`]: crate::either_biased_const()
+#[derive(Copy, Clone, Default, Debug)]
+pub struct Biased<const LEFT_FIRST: bool>;
+
+/// Polls the left future first, and then alternates which future gets polled first, as
+/// [`either_alternate()`] does.
+///
+/// [`either_alternate()`]: crate::either_alternate()
+#[derive(Copy, Clone, Debug)]
+pub struct Alternate {
+    next: Side,
+}
+
+#[cfg(feature = "fair-lite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fair-lite")))]
+/// Randomly chooses which future gets polled first, as [`either_fair()`] does.
+///
+/// Without the `fair` feature (i.e. with only `fair-lite`), this doesn't depend on `fastrand` and
+/// instead alternates, globally, which future gets polled first.
+///
+/// With the `fair-secure` feature, the choice is instead made using the entropy source of the
+/// operating system (through `getrandom`), which is slower but can't be predicted, falling back to
+/// the above if it fails.
+///
+/// In all cases, with the `std` feature, a custom source can be used instead with
+/// `Random::set_source()`.
+///
+/// [`either_fair()`]: crate::either_fair()
+#[derive(Copy, Clone, Default, Debug)]
+pub struct Random;
+
+/// Wraps another policy, making sure that it never chooses to poll the same future first more than
+/// a given number of times in a row, to prevent long streaks (e.g. from [`Random`]) from starving
+/// the other future.
+///
+/// ## Example
+///
+/// ```rust
+/// use futures_either::order::{LeftFirst, PollOrder, StreakLimit};
+/// use futures_either::Side;
+///
+/// let mut order = StreakLimit::new(LeftFirst, 2);
+/// assert_eq!(order.first(), Side::Left);
+/// assert_eq!(order.first(), Side::Left);
+/// assert_eq!(order.first(), Side::Right);
+/// assert_eq!(order.first(), Side::Left);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct StreakLimit<P> {
+    inner: P,
+    max: usize,
+    last: Side,
+    streak: usize,
+}
+
+/// Calls a closure to decide which future gets polled first, as [`either_by()`] does.
+///
+/// [`either_by()`]: crate::either_by()
+#[derive(Copy, Clone)]
+pub struct FromFn<F>(pub F);
+
+// ========================================== Alternate ========================================= \\
+
+impl Alternate {
+    /// Returns a new policy polling the left future first the first time.
+    pub const fn new() -> Self {
+        Alternate { next: Side::Left }
+    }
+}
+
+impl Default for Alternate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ========================================= StreakLimit ======================================== \\
+
+impl<P> StreakLimit<P> {
+    /// Returns a new policy using `inner`, unless it has chosen the same future more than `max`
+    /// times in a row, in which case the other future is polled first.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `max` is `0`.
+    pub fn new(inner: P, max: usize) -> Self {
+        assert!(max > 0, "`max` must be greater than 0");
+        StreakLimit { inner, max, last: Side::Left, streak: 0 }
+    }
+
+    /// Returns the wrapped policy.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+// =========================================== Random =========================================== \\
+
+/// The source set with [`Random::set_source()`], if any.
+#[cfg(all(feature = "fair-lite", feature = "std"))]
+static SOURCE: RwLock<Option<fn() -> bool>> = RwLock::new(None);
+
+#[cfg(all(feature = "fair", feature = "fair-secure"))]
+std::thread_local! {
+    /// Whether [`Random::seed()`] has been called on the current thread.
+    static SEEDED: Cell<bool> = const { Cell::new(false) };
+}
+
+#[cfg(feature = "fair")]
+std::thread_local! {
+    /// The seed epoch of the current thread (see [`Random::epoch()`]), or `0` if it hasn't been
+    /// assigned one yet.
+    static EPOCH: Cell<u64> = const { Cell::new(0) };
+}
+
+/// The last seed epoch assigned to a thread.
+#[cfg(feature = "fair")]
+static LAST_EPOCH: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(feature = "fair-lite")]
+impl Random {
+    /// Makes this policy, [`either_fair()`] and the other "fair" futures of this crate call
+    /// `source` to choose which future to poll first (polling the left one first if it returns
+    /// `true`), instead of using `fastrand` or alternating.
+    ///
+    /// This allows targets without a source of entropy available to `fastrand` to use, e.g., a
+    /// hardware generator. Without the `std` feature, the same can be achieved by registering a
+    /// custom `getrandom` backend and enabling the `fair-secure` feature, or for a single race by
+    /// using [`either_with()`] with [`from_fn()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures_lite::future;
+    /// use futures_either::order::{PollOrder, Random};
+    /// use futures_either::{either_fair, Either, Side};
+    ///
+    /// Random::set_source(|| false);
+    /// assert_eq!(Random.first(), Side::Right);
+    ///
+    /// Random::set_source(|| true);
+    /// for _ in 0..8 {
+    ///     let out = future::block_on(either_fair(async { 42 }, async { false }));
+    ///     assert_eq!(out, Either::Left(42));
+    /// }
+    /// ```
+    ///
+    /// [`either_fair()`]: crate::either_fair()
+    /// [`either_with()`]: crate::either_with()
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn set_source(source: fn() -> bool) {
+        *SOURCE.write().unwrap_or_else(PoisonError::into_inner) = Some(source);
+    }
+
+    fn source() -> Option<fn() -> bool> {
+        #[cfg(feature = "std")]
+        {
+            *SOURCE.read().unwrap_or_else(PoisonError::into_inner)
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            None
+        }
+    }
+
+    fn flip() -> bool {
+        if let Some(source) = Self::source() {
+            return source();
+        }
+
+        #[cfg(feature = "fair-secure")]
+        if !Self::seeded() {
+            let mut byte = [0];
+            if getrandom::getrandom(&mut byte).is_ok() {
+                return byte[0] & 1 == 1;
+            }
+        }
+
+        #[cfg(feature = "fair")]
+        {
+            fastrand::bool()
+        }
+
+        #[cfg(not(feature = "fair"))]
+        {
+            static LEFT_FIRST: AtomicBool = AtomicBool::new(true);
+
+            // Races between threads only make the order less regular, which is fine; this avoids
+            // requiring atomic read-modify-write operations.
+            let left_first = LEFT_FIRST.load(Ordering::Relaxed);
+            LEFT_FIRST.store(!left_first, Ordering::Relaxed);
+            left_first
+        }
+    }
+
+    /// Returns a number between `0.0` (inclusive) and `1.0` (exclusive), drawn from the same
+    /// source as [`Random::flip()`]: the custom source if one has been set, the entropy source of
+    /// the operating system on threads which haven't been seeded with the `fair-secure` feature,
+    /// or the thread-local generator.
+    ///
+    /// The custom source gets called once per bit, each `true` making the number lower, so that a
+    /// source always returning `true` (i.e. asking to poll the left future first) returns `0.0`.
+    #[cfg(feature = "fair")]
+    pub(crate) fn f32() -> f32 {
+        const BITS: u32 = f32::MANTISSA_DIGITS;
+
+        if let Some(source) = Self::source() {
+            let bits = (0..BITS).fold(0u32, |bits, _| bits << 1 | u32::from(!source()));
+            return bits as f32 / (1u32 << BITS) as f32;
+        }
+
+        #[cfg(feature = "fair-secure")]
+        if !Self::seeded() {
+            let mut bytes = [0; 4];
+            if getrandom::getrandom(&mut bytes).is_ok() {
+                let bits = u32::from_le_bytes(bytes) >> (u32::BITS - BITS);
+                return bits as f32 / (1u32 << BITS) as f32;
+            }
+        }
+
+        fastrand::f32()
+    }
+
+    /// Returns whether [`EitherFair`] can cache a generator seeded with [`Random::seed_state()`]
+    /// instead of asking this policy every time it gets polled, which it can't when a custom
+    /// source has been set or when the entropy source of the operating system is used.
+    ///
+    /// [`EitherFair`]: crate::futs::EitherFair
+    pub(crate) fn cacheable() -> bool {
+        if Self::source().is_some() {
+            return false;
+        }
+
+        #[cfg(feature = "fair-secure")]
+        if !Self::seeded() {
+            return false;
+        }
+
+        true
+    }
+
+    /// Returns whether [`Random::seed()`] has been called on the current thread.
+    #[cfg(feature = "fair-secure")]
+    fn seeded() -> bool {
+        #[cfg(feature = "fair")]
+        {
+            SEEDED.with(Cell::get)
+        }
+
+        #[cfg(not(feature = "fair"))]
+        {
+            false
+        }
+    }
+
+    /// Returns the seed epoch of the current thread, which is never `0`, differs from the ones of
+    /// the other threads, and changes every time [`Random::seed()`] gets called on it, so that
+    /// [`EitherFair`] can seed its cached generator again when it does or when it gets moved to
+    /// another thread.
+    ///
+    /// Without the `fair` feature, there is no thread-local generator to follow, so it is always
+    /// `1`.
+    ///
+    /// [`EitherFair`]: crate::futs::EitherFair
+    pub(crate) fn epoch() -> u64 {
+        #[cfg(feature = "fair")]
+        {
+            EPOCH.with(|epoch| {
+                if epoch.get() == 0 {
+                    epoch.set(next_epoch());
+                }
+
+                epoch.get()
+            })
+        }
+
+        #[cfg(not(feature = "fair"))]
+        {
+            1
+        }
+    }
+
+    /// Returns a non-zero seed for the generator cached by [`EitherFair`], when
+    /// [`Random::cacheable()`] returns `true`.
+    ///
+    /// [`EitherFair`]: crate::futs::EitherFair
+    pub(crate) fn seed_state() -> u64 {
+        #[cfg(feature = "fair")]
+        {
+            fastrand::u64(..) | 1
+        }
+
+        #[cfg(not(feature = "fair"))]
+        {
+            static NEXT: AtomicUsize = AtomicUsize::new(1);
+
+            let next = NEXT.load(Ordering::Relaxed);
+            NEXT.store(next.wrapping_add(1), Ordering::Relaxed);
+            (next as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1
+        }
+    }
+}
+
+#[cfg(feature = "fair")]
+impl Random {
+    /// Seeds the thread-local generator used by this policy, [`either_fair()`] and the other
+    /// "fair" futures of this crate, making the choices they make on the current thread
+    /// reproducible.
+    ///
+    /// With the `fair-secure` feature, this also makes them stop using the entropy source of the
+    /// operating system on the current thread. Other threads are unaffected in both cases, and a
+    /// source set with `Random::set_source()` takes precedence over the seed.
+    ///
+    /// Fair races cache a generator seeded from the thread-local one when first polled. Races
+    /// which already did, including ones created before calling this, seed it again the next time
+    /// they get polled on the current thread, and so do races polled on another thread than the
+    /// one they were last polled on, so that their choices always follow the seed of the thread
+    /// polling them.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use std::thread;
+    ///
+    /// use futures_either::order::{PollOrder, Random};
+    ///
+    /// Random::seed(42);
+    /// let first = (0..64).map(|_| Random.first()).collect::<Vec<_>>();
+    ///
+    /// Random::seed(42);
+    /// let second = (0..64).map(|_| Random.first()).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(first, second);
+    ///
+    /// // Threads which didn't get seeded are unaffected.
+    /// let other = thread::spawn(|| (0..64).map(|_| Random.first()).collect::<Vec<_>>());
+    /// assert_ne!(other.join().unwrap(), first);
+    /// ```
+    ///
+    /// [`either_fair()`]: crate::either_fair()
+    pub fn seed(seed: u64) {
+        #[cfg(feature = "fair-secure")]
+        SEEDED.with(|seeded| seeded.set(true));
+
+        EPOCH.with(|epoch| epoch.set(next_epoch()));
+        fastrand::seed(seed);
+    }
+}
+
+/// Returns a new seed epoch, different from all the ones returned before.
+#[cfg(feature = "fair")]
+fn next_epoch() -> u64 {
+    LAST_EPOCH.fetch_add(1, Ordering::Relaxed) as u64 + 1
+}
+
+// =========================================== FromFn =========================================== \\
+
+/// Returns a policy calling `f` to decide which future gets polled first.
+pub const fn from_fn<F>(f: F) -> FromFn<F>
+where
+    F: FnMut() -> Side,
+{
+    FromFn(f)
+}
+
+// ======================================= impl PollOrder ======================================= \\
+
+impl PollOrder for LeftFirst {
+    fn first(&mut self) -> Side {
+        Side::Left
+    }
+}
+
+impl PollOrder for RightFirst {
+    fn first(&mut self) -> Side {
+        Side::Right
+    }
+}
+
+impl<const LEFT_FIRST: bool> PollOrder for Biased<LEFT_FIRST> {
+    fn first(&mut self) -> Side {
+        if LEFT_FIRST {
+            Side::Left
+        } else {
+            Side::Right
+        }
+    }
+}
+
+impl PollOrder for Alternate {
+    fn first(&mut self) -> Side {
+        let first = self.next;
+        self.next = first.other();
+        first
+    }
+}
+
+#[cfg(feature = "fair-lite")]
+impl PollOrder for Random {
+    fn first(&mut self) -> Side {
+        if Random::flip() {
+            Side::Left
+        } else {
+            Side::Right
+        }
+    }
+}
+
+#[cfg(feature = "fair")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fair")))]
+/// Randomly chooses which future gets polled first, using this generator instead of the
+/// thread-local one.
+impl PollOrder for fastrand::Rng {
+    fn first(&mut self) -> Side {
+        if self.bool() {
+            Side::Left
+        } else {
+            Side::Right
+        }
+    }
+}
+
+impl<P> PollOrder for StreakLimit<P>
+where
+    P: PollOrder,
+{
+    fn first(&mut self) -> Side {
+        let mut first = self.inner.first();
+        if first == self.last && self.streak >= self.max {
+            first = first.other();
+        }
+
+        if first == self.last {
+            self.streak += 1;
+        } else {
+            self.last = first;
+            self.streak = 1;
+        }
+
+        first
+    }
+}
+
+impl<F> PollOrder for FromFn<F>
+where
+    F: FnMut() -> Side,
+{
+    fn first(&mut self) -> Side {
+        (self.0)()
+    }
+}
+
+/// Always polls the future on this side first.
+impl PollOrder for Side {
+    fn first(&mut self) -> Side {
+        *self
+    }
+}
+
+impl<P> PollOrder for &mut P
+where
+    P: PollOrder + ?Sized,
+{
+    fn first(&mut self) -> Side {
+        (**self).first()
+    }
+}
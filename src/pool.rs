@@ -0,0 +1,191 @@
+/**************************************************************************************************
+ *                                                                                                *
+ * This Source Code Form is subject to the terms of the Mozilla Public                            *
+ * License, v. 2.0. If a copy of the MPL was not distributed with this                            *
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.                                       *
+ *                                                                                                *
+ **************************************************************************************************/
+
+// ======================================== Documentation ======================================= \\
+
+//! A dynamic set of futures raced against each other.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use futures_lite::future;
+//! use futures_either::pool::EitherPool;
+//!
+//! async fn get(out: Option<i32>) -> i32 {
+//!     match out {
+//!         Some(out) => out,
+//!         None => future::pending().await,
+//!     }
+//! }
+//!
+//! # future::block_on(async {
+//! #
+//! let mut pool = EitherPool::new();
+//! let first = pool.insert(get(None));
+//! let second = pool.insert(get(Some(42)));
+//!
+//! assert_eq!(pool.race().await, Some((second, 42)));
+//! assert_eq!(pool.len(), 1);
+//!
+//! assert!(pool.remove(first));
+//! assert_eq!(pool.race().await, None);
+//! #
+//! # });
+//! ```
+
+// =========================================== Imports ========================================== \\
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+// ============================================ Types =========================================== \\
+
+/// A set of futures, to which futures can be added at any time, and whose next future to complete
+/// can be awaited using [`race()`].
+///
+/// Each future is identified by the id returned by [`insert()`], which might be re-used once the
+/// future has completed or has been removed. Futures which haven't completed when another one does
+/// stay in the pool and keep their progress.
+///
+/// The pool will always poll its futures in the order of their ids.
+///
+/// [`race()`]: EitherPool::race()
+/// [`insert()`]: EitherPool::insert()
+pub struct EitherPool<F> {
+    futs: Vec<Option<Pin<Box<F>>>>,
+    free: Vec<usize>,
+}
+
+/// The [`Future`] returned by [`EitherPool::race()`].
+///
+/// [`Future`]: core::future::Future
+pub struct Race<'pool, F> {
+    pool: &'pool mut EitherPool<F>,
+}
+
+// ========================================= EitherPool ========================================= \\
+
+impl<F> EitherPool<F> {
+    /// Returns a new empty pool.
+    pub fn new() -> Self {
+        EitherPool {
+            futs: Vec::new(),
+            free: Vec::new(),
+        }
+    }
+
+    /// Returns the number of futures in the pool.
+    pub fn len(&self) -> usize {
+        self.futs.len() - self.free.len()
+    }
+
+    /// Returns `true` if the pool doesn't contain any future.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the pool contains a future with the given id.
+    pub fn contains(&self, id: usize) -> bool {
+        matches!(self.futs.get(id), Some(Some(_)))
+    }
+
+    /// Adds a future to the pool, returning its id.
+    pub fn insert(&mut self, fut: F) -> usize {
+        let fut = Some(Box::pin(fut));
+
+        if let Some(id) = self.free.pop() {
+            self.futs[id] = fut;
+            id
+        } else {
+            self.futs.push(fut);
+            self.futs.len() - 1
+        }
+    }
+
+    /// Drops the future with the given id, returning `true` if the pool contained it.
+    pub fn remove(&mut self, id: usize) -> bool {
+        if let Some(slot @ Some(_)) = self.futs.get_mut(id) {
+            *slot = None;
+            self.free.push(id);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Drops all the futures in the pool.
+    pub fn clear(&mut self) {
+        self.futs.clear();
+        self.free.clear();
+    }
+}
+
+impl<F> EitherPool<F>
+where
+    F: Future,
+{
+    /// Returns a future returning the id and output of the next future of the pool to complete,
+    /// or `None` if the pool is empty.
+    pub fn race(&mut self) -> Race<'_, F> {
+        Race { pool: self }
+    }
+
+    /// Polls the futures of the pool, returning the id and output of the first one to complete,
+    /// or `None` if the pool is empty.
+    pub fn poll_race(&mut self, ctx: &mut Context) -> Poll<Option<(usize, F::Output)>> {
+        if self.is_empty() {
+            return Poll::Ready(None);
+        }
+
+        for (id, slot) in self.futs.iter_mut().enumerate() {
+            if let Some(fut) = slot {
+                if let Poll::Ready(out) = fut.as_mut().poll(ctx) {
+                    *slot = None;
+                    self.free.push(id);
+
+                    return Poll::Ready(Some((id, out)));
+                }
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<F> Default for EitherPool<F> {
+    fn default() -> Self {
+        EitherPool::new()
+    }
+}
+
+impl<F> Extend<F> for EitherPool<F> {
+    fn extend<I>(&mut self, futs: I)
+    where
+        I: IntoIterator<Item = F>,
+    {
+        for fut in futs {
+            self.insert(fut);
+        }
+    }
+}
+
+// ========================================= impl Future ======================================== \\
+
+impl<F> Future for Race<'_, F>
+where
+    F: Future,
+{
+    type Output = Option<(usize, F::Output)>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        self.get_mut().pool.poll_race(ctx)
+    }
+}
@@ -0,0 +1,50 @@
+/**************************************************************************************************
+ *                                                                                                *
+ * This Source Code Form is subject to the terms of the Mozilla Public                            *
+ * License, v. 2.0. If a copy of the MPL was not distributed with this                            *
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.                                       *
+ *                                                                                                *
+ **************************************************************************************************/
+
+// ======================================== Documentation ======================================= \\
+
+//! The most commonly used items of this crate, to be imported with a single `use`.
+//!
+//! [`FutureExt`] isn't part of it, as its methods would be ambiguous with the ones of the
+//! extension traits of `futures` and `futures-lite`, which are usually glob-imported too.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use futures_lite::future;
+//! use futures_lite::prelude::*;
+//! use futures_either::prelude::*;
+//!
+//! # future::block_on(async {
+//! #
+//! let out = either(async { 42 }, async { false })
+//!     .map_left(|left| left * 2)
+//!     .await;
+//! assert_eq!(out, Either::Left(84));
+//!
+//! let out = either!(future::pending::<bool>(), async { 42 }, async { false }).await;
+//! assert_eq!(out, Either3::Second(42));
+//! #
+//! # });
+//! ```
+//!
+//! [`FutureExt`]: crate::FutureExt
+
+// ========================================= Re-exports ========================================= \\
+
+pub use crate::{either, try_either};
+pub use crate::{both, either_biased, either_or_both, race, race_indexed, race_ok};
+pub use crate::{try_both, try_race};
+pub use crate::{Either, Either3, EitherOrBoth, Side};
+pub use crate::{EitherBuilder, EitherExt, Or, Race, TryRace};
+pub use crate::order::PollOrder;
+
+#[cfg(feature = "fair-lite")]
+pub use crate::{either_fair, race_fair, race_indexed_fair, race_ok_fair, try_either_fair};
+#[cfg(feature = "fair-lite")]
+pub use crate::RaceFair;
@@ -0,0 +1,131 @@
+/**************************************************************************************************
+ *                                                                                                *
+ * This Source Code Form is subject to the terms of the Mozilla Public                            *
+ * License, v. 2.0. If a copy of the MPL was not distributed with this                            *
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.                                       *
+ *                                                                                                *
+ **************************************************************************************************/
+
+// ======================================== Documentation ======================================= \\
+
+//! Executors to which futures can be handed over, to be used with [`either_detach()`] and
+//! [`either_spawned()`].
+//!
+//! [`Spawn`] is implemented for closures taking the future, and, with the `tokio` feature, for
+//! [`tokio::runtime::Handle`], [`tokio::task::LocalSet`] and `TokioLocal`, the last two allowing
+//! to spawn futures which don't implement [`Send`] (e.g. on thread-per-core runtimes or GUI
+//! threads).
+//!
+//! ## Example
+//!
+//! ```rust
+//! use futures_lite::future;
+//! use futures_either::spawn::Spawn;
+//!
+//! let mut spawned = Vec::new();
+//! let mut spawner = |fut| spawned.push(fut);
+//!
+//! spawner.spawn(future::ready(42));
+//! assert_eq!(spawned.len(), 1);
+//! ```
+//!
+//! [`either_detach()`]: crate::either_detach()
+//! [`either_spawned()`]: crate::either_spawned()
+//! [`tokio::runtime::Handle`]: https://docs.rs/tokio/1/tokio/runtime/struct.Handle.html
+//! [`tokio::task::LocalSet`]: https://docs.rs/tokio/1/tokio/task/struct.LocalSet.html
+
+// =========================================== Imports ========================================== \\
+
+#[cfg(feature = "tokio")]
+use core::future::Future;
+
+// ============================================ Types =========================================== \\
+
+/// An executor to which futures of type `F` can be handed over, to keep getting polled in the
+/// background.
+pub trait Spawn<F> {
+    /// Hands `fut` over to the executor.
+    fn spawn(&mut self, fut: F);
+}
+
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+/// Spawns futures on the current [`LocalSet`] with [`tokio::task::spawn_local()`], without
+/// requiring them to implement [`Send`].
+///
+/// ## Panics
+///
+/// Panics when spawning a future outside of a [`LocalSet`].
+///
+/// ## Example
+///
+/// ```rust
+/// use std::rc::Rc;
+///
+/// use futures_either::spawn::{Spawn, TokioLocal};
+///
+/// let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// let local = tokio::task::LocalSet::new();
+///
+/// let out = Rc::new(42);
+/// local.block_on(&rt, async move {
+///     TokioLocal.spawn(async move { assert_eq!(*out, 42) });
+/// });
+/// ```
+///
+/// [`LocalSet`]: tokio::task::LocalSet
+/// [`tokio::task::spawn_local()`]: tokio::task::spawn_local()
+#[derive(Copy, Clone, Default, Debug)]
+pub struct TokioLocal;
+
+// ========================================= impl Spawn ========================================= \\
+
+impl<S, F> Spawn<F> for S
+where
+    S: FnMut(F),
+{
+    fn spawn(&mut self, fut: F) {
+        self(fut)
+    }
+}
+
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+/// Spawns the futures on the runtime, detaching their [`JoinHandle`]s.
+///
+/// [`JoinHandle`]: tokio::task::JoinHandle
+impl<F> Spawn<F> for tokio::runtime::Handle
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    fn spawn(&mut self, fut: F) {
+        tokio::runtime::Handle::spawn(self, fut);
+    }
+}
+
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+/// Spawns the futures on this set, detaching their [`JoinHandle`]s.
+///
+/// [`JoinHandle`]: tokio::task::JoinHandle
+impl<F> Spawn<F> for &tokio::task::LocalSet
+where
+    F: Future + 'static,
+    F::Output: 'static,
+{
+    fn spawn(&mut self, fut: F) {
+        tokio::task::LocalSet::spawn_local(self, fut);
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<F> Spawn<F> for TokioLocal
+where
+    F: Future + 'static,
+    F::Output: 'static,
+{
+    fn spawn(&mut self, fut: F) {
+        tokio::task::spawn_local(fut);
+    }
+}
@@ -0,0 +1,263 @@
+/**************************************************************************************************
+ *                                                                                                *
+ * This Source Code Form is subject to the terms of the Mozilla Public                            *
+ * License, v. 2.0. If a copy of the MPL was not distributed with this                            *
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.                                       *
+ *                                                                                                *
+ **************************************************************************************************/
+
+// ======================================== Documentation ======================================= \\
+
+//! Timers creating the delays futures get raced against, to be used with [`either_timeout()`] and
+//! [`either_at()`], allowing them to work with any runtime.
+//!
+//! [`Timer`] is implemented, behind features, for common runtimes:
+//!
+//! - `TokioTimer` uses `tokio::time` (with the `tokio` feature).
+//! - `AsyncIoTimer` uses `async_io::Timer`, as `smol` does (with the `async-io` feature).
+//! - `FuturesTimer` uses `futures_timer::Delay` (with the `futures-timer` feature).
+//! - `EmbassyTimer` uses `embassy_time::Timer`, without requiring `std` (with the `embassy-time`
+//!   feature).
+//!
+//! ## Example
+//!
+//! ```rust
+//! use core::time::Duration;
+//!
+//! use futures_lite::future;
+//! use futures_either::either_timeout;
+//! use futures_either::time::{Elapsed, Timer};
+//!
+//! /// A timer whose delays have always elapsed.
+//! struct Elapsing;
+//!
+//! impl Timer for Elapsing {
+//!     type Instant = ();
+//!     type Delay = future::Ready<()>;
+//!
+//!     fn delay(&mut self, _: Duration) -> Self::Delay {
+//!         future::ready(())
+//!     }
+//!
+//!     fn delay_until(&mut self, _: ()) -> Self::Delay {
+//!         future::ready(())
+//!     }
+//! }
+//!
+//! # future::block_on(async {
+//! #
+//! let out = either_timeout(async { 42 }, Duration::from_secs(1), Elapsing).await;
+//! assert_eq!(out, Ok(42));
+//!
+//! let out = either_timeout(future::pending::<i32>(), Duration::from_secs(1), Elapsing).await;
+//! assert_eq!(out, Err(Elapsed));
+//! #
+//! # });
+//! ```
+//!
+//! [`either_timeout()`]: crate::either_timeout()
+//! [`either_at()`]: crate::either_at()
+
+// =========================================== Imports ========================================== \\
+
+#[cfg(feature = "embassy-time")]
+use core::convert::TryFrom;
+use core::future::Future;
+use core::time::Duration;
+
+// ============================================ Types =========================================== \\
+
+/// A timer creating futures completing after a given duration or at a given instant.
+pub trait Timer {
+    /// The type of the instants the timer can wait for.
+    type Instant;
+
+    /// The type of the futures returned by the timer.
+    type Delay: Future;
+
+    /// Returns a future completing once `duration` has elapsed.
+    fn delay(&mut self, duration: Duration) -> Self::Delay;
+
+    /// Returns a future completing once `deadline` has been reached.
+    fn delay_until(&mut self, deadline: Self::Instant) -> Self::Delay;
+}
+
+/// The error returned by [`either_timeout()`] and [`either_at()`] when the delay has elapsed before
+/// the future completed.
+///
+/// [`either_timeout()`]: crate::either_timeout()
+/// [`either_at()`]: crate::either_at()
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Elapsed;
+
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+/// A timer using [`tokio::time::sleep()`] and [`tokio::time::sleep_until()`].
+///
+/// The delays must be created and polled within a Tokio runtime with the time driver enabled.
+///
+/// ## Example
+///
+/// ```rust
+/// use core::time::Duration;
+///
+/// use futures_lite::future;
+/// use futures_either::either_timeout;
+/// use futures_either::time::{Elapsed, TokioTimer};
+///
+/// let rt = tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap();
+/// rt.block_on(async {
+///     let out = either_timeout(future::pending::<i32>(), Duration::from_millis(1), TokioTimer);
+///     assert_eq!(out.await, Err(Elapsed));
+/// });
+/// ```
+///
+/// [`tokio::time::sleep()`]: tokio::time::sleep()
+/// [`tokio::time::sleep_until()`]: tokio::time::sleep_until()
+#[derive(Copy, Clone, Default, Debug)]
+pub struct TokioTimer;
+
+#[cfg(feature = "async-io")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async-io")))]
+/// A timer using [`async_io::Timer::after()`] and [`async_io::Timer::at()`], which works with
+/// `smol` (and any other executor, as `async-io` drives its timers itself).
+///
+/// ## Example
+///
+/// ```rust
+/// use core::time::Duration;
+///
+/// use futures_lite::future;
+/// use futures_either::either_timeout;
+/// use futures_either::time::{AsyncIoTimer, Elapsed};
+///
+/// # future::block_on(async {
+/// #
+/// let out = either_timeout(future::pending::<i32>(), Duration::from_millis(1), AsyncIoTimer);
+/// assert_eq!(out.await, Err(Elapsed));
+/// #
+/// # });
+/// ```
+///
+/// [`async_io::Timer::after()`]: async_io::Timer::after()
+/// [`async_io::Timer::at()`]: async_io::Timer::at()
+#[derive(Copy, Clone, Default, Debug)]
+pub struct AsyncIoTimer;
+
+#[cfg(feature = "futures-timer")]
+#[cfg_attr(docsrs, doc(cfg(feature = "futures-timer")))]
+/// A timer using [`futures_timer::Delay`], which works with any executor.
+///
+/// As `futures-timer` doesn't support waiting for an instant, [`delay_until()`] creates a delay
+/// for the duration remaining until the deadline when it gets called.
+///
+/// ## Example
+///
+/// ```rust
+/// use core::time::Duration;
+///
+/// use futures_lite::future;
+/// use futures_either::either_timeout;
+/// use futures_either::time::{Elapsed, FuturesTimer};
+///
+/// # future::block_on(async {
+/// #
+/// let out = either_timeout(future::pending::<i32>(), Duration::from_millis(1), FuturesTimer);
+/// assert_eq!(out.await, Err(Elapsed));
+/// #
+/// # });
+/// ```
+///
+/// [`futures_timer::Delay`]: futures_timer::Delay
+/// [`delay_until()`]: Timer::delay_until()
+#[derive(Copy, Clone, Default, Debug)]
+pub struct FuturesTimer;
+
+#[cfg(feature = "embassy-time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "embassy-time")))]
+/// A timer using [`embassy_time::Timer::after()`] and [`embassy_time::Timer::at()`], which works
+/// on microcontrollers, without `std`.
+///
+/// Durations too long to be represented by [`embassy_time::Duration`] are saturated.
+///
+/// [`embassy_time::Timer::after()`]: embassy_time::Timer::after()
+/// [`embassy_time::Timer::at()`]: embassy_time::Timer::at()
+/// [`embassy_time::Duration`]: embassy_time::Duration
+#[derive(Copy, Clone, Default, Debug)]
+pub struct EmbassyTimer;
+
+// ========================================= impl Timer ========================================= \\
+
+impl<T> Timer for &mut T
+where
+    T: Timer + ?Sized,
+{
+    type Instant = T::Instant;
+    type Delay = T::Delay;
+
+    fn delay(&mut self, duration: Duration) -> Self::Delay {
+        (**self).delay(duration)
+    }
+
+    fn delay_until(&mut self, deadline: Self::Instant) -> Self::Delay {
+        (**self).delay_until(deadline)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Timer for TokioTimer {
+    type Instant = tokio::time::Instant;
+    type Delay = tokio::time::Sleep;
+
+    fn delay(&mut self, duration: Duration) -> Self::Delay {
+        tokio::time::sleep(duration)
+    }
+
+    fn delay_until(&mut self, deadline: Self::Instant) -> Self::Delay {
+        tokio::time::sleep_until(deadline)
+    }
+}
+
+#[cfg(feature = "async-io")]
+impl Timer for AsyncIoTimer {
+    type Instant = std::time::Instant;
+    type Delay = async_io::Timer;
+
+    fn delay(&mut self, duration: Duration) -> Self::Delay {
+        async_io::Timer::after(duration)
+    }
+
+    fn delay_until(&mut self, deadline: Self::Instant) -> Self::Delay {
+        async_io::Timer::at(deadline)
+    }
+}
+
+#[cfg(feature = "futures-timer")]
+impl Timer for FuturesTimer {
+    type Instant = std::time::Instant;
+    type Delay = futures_timer::Delay;
+
+    fn delay(&mut self, duration: Duration) -> Self::Delay {
+        futures_timer::Delay::new(duration)
+    }
+
+    fn delay_until(&mut self, deadline: Self::Instant) -> Self::Delay {
+        let now = std::time::Instant::now();
+        futures_timer::Delay::new(deadline.saturating_duration_since(now))
+    }
+}
+
+#[cfg(feature = "embassy-time")]
+impl Timer for EmbassyTimer {
+    type Instant = embassy_time::Instant;
+    type Delay = embassy_time::Timer;
+
+    fn delay(&mut self, duration: Duration) -> Self::Delay {
+        let duration = embassy_time::Duration::try_from(duration);
+        embassy_time::Timer::after(duration.unwrap_or(embassy_time::Duration::MAX))
+    }
+
+    fn delay_until(&mut self, deadline: Self::Instant) -> Self::Delay {
+        embassy_time::Timer::at(deadline)
+    }
+}